@@ -448,12 +448,13 @@ pub fn find_type_def_symbols(
                     stack.push(&t.value);
                 }
             }
-            Function(arguments, result) => {
+            Function(arguments, result, effect) => {
                 for t in arguments.iter() {
                     stack.push(&t.value);
                 }
 
                 stack.push(&result.value);
+                stack.extend(effect.iter().map(|t| &t.value));
             }
             BoundVariable(_) => {}
             As(actual, _, _) => {
@@ -554,7 +555,8 @@ fn can_annotation_help(
     use roc_parse::ast::TypeAnnotation::*;
 
     match annotation {
-        Function(argument_types, return_type) => {
+        // Effects aren't part of the type system yet, so an effect annotation is parsed but ignored here.
+        Function(argument_types, return_type, _effect) => {
             let mut args = Vec::new();
 
             for arg in *argument_types {
@@ -3222,8 +3222,8 @@ fn to_pending_value_def<'a>(
 
             for loc_name in exposed_names {
                 let exposed_name = loc_name.value.item();
-                let name = exposed_name.as_str();
-                let ident = Ident::from(name);
+                let name = exposed_name.name.as_str();
+                let ident = Ident::from(exposed_name.local_name().as_str());
 
                 match exposed_ids.get_id(name) {
                     Some(ident_id) => {
@@ -3248,7 +3248,7 @@ fn to_pending_value_def<'a>(
                     None => {
                         env.problem(Problem::RuntimeError(RuntimeError::ValueNotExposed {
                             module_name: module_name.clone(),
-                            ident,
+                            ident: Ident::from(name),
                             region: loc_name.region,
                             exposed_values: exposed_ids.exposed_values(),
                         }))
@@ -349,6 +349,7 @@ pub fn desugar_expr<'a>(
         | EmptyRecordBuilder(_)
         | SingleFieldRecordBuilder(_)
         | OptionalFieldInRecordBuilder { .. }
+        | Spread(_)
         | Tag(_)
         | OpaqueRef(_)
         | Crash => loc_expr,
@@ -534,11 +535,13 @@ pub fn desugar_expr<'a>(
                 desugar_expr(env, scope, loc_ret),
             ),
         }),
-        Backpassing(loc_patterns, loc_body, loc_ret) => {
+        Backpassing(lhs, loc_body, loc_ret) => {
             // loc_patterns <- loc_body
             //
             // loc_ret
 
+            let loc_patterns = lhs.patterns;
+
             let problem_region = Region::span_across(
                 &Region::across_all(loc_patterns.iter().map(|loc_pattern| &loc_pattern.region)),
                 &loc_body.region,
@@ -548,8 +551,15 @@ pub fn desugar_expr<'a>(
             // first desugar the body, because it may contain |>
             let desugared_body = desugar_expr(env, scope, loc_body);
 
-            let desugared_ret = desugar_expr(env, scope, loc_ret);
-            let desugared_loc_patterns = desugar_loc_patterns(env, scope, loc_patterns);
+            let (desugared_loc_patterns, desugared_ret) = match (&lhs.ann, loc_patterns) {
+                (Some(loc_ann), [loc_pattern]) => {
+                    desugar_annotated_backpassing(env, scope, loc_pattern, loc_ann, loc_ret)
+                }
+                _ => (
+                    desugar_loc_patterns(env, scope, loc_patterns),
+                    desugar_expr(env, scope, loc_ret),
+                ),
+            };
             let closure = Expr::Closure(desugared_loc_patterns, desugared_ret);
             let loc_closure = Loc::at(loc_expr.region, closure);
 
@@ -1253,6 +1263,58 @@ pub fn desugar_record_destructures<'a>(
     field_patterns.replace_items(allocated.into_bump_slice())
 }
 
+/// Desugars the closure for an annotated backpassing pattern, `pattern : ann <- body`,
+/// so that the annotation is checked by an annotated def at the start of the closure:
+/// \tmpVar ->
+///     pattern : ann
+///     pattern = tmpVar
+///     ret
+fn desugar_annotated_backpassing<'a>(
+    env: &mut Env<'a>,
+    scope: &mut Scope,
+    loc_pattern: &'a Loc<Pattern<'a>>,
+    loc_ann: &'a Loc<TypeAnnotation<'a>>,
+    loc_ret: &'a Loc<Expr<'a>>,
+) -> (&'a [Loc<Pattern<'a>>], &'a Loc<Expr<'a>>) {
+    let region = loc_pattern.region;
+    let ident = env.arena.alloc(scope.gen_unique_symbol_name().to_string());
+
+    let tmp_var = env.arena.alloc(Loc {
+        value: Var {
+            module_name: "",
+            ident,
+        },
+        region,
+    });
+
+    let value_def = ValueDef::AnnotatedBody {
+        ann_pattern: loc_pattern,
+        ann_type: loc_ann,
+        lines_between: &[],
+        body_pattern: loc_pattern,
+        body_expr: tmp_var,
+    };
+
+    let defs = env.arena.alloc(Defs::default());
+    defs.push_value_def(
+        value_def,
+        Region::span_across(&region, &loc_ann.region),
+        &[],
+        &[],
+    );
+
+    let ret = env
+        .arena
+        .alloc(Loc::at(loc_ret.region, Defs(defs, loc_ret)));
+
+    let closure_pattern = Loc::at(region, Pattern::Identifier { ident });
+
+    (
+        env.arena.alloc([closure_pattern]),
+        desugar_expr(env, scope, ret),
+    )
+}
+
 /// Desugars a `dbg expr` expression into a statement block that prints and returns the
 /// value produced by `expr`. Essentially:
 /// (
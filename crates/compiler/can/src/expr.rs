@@ -728,7 +728,7 @@ pub fn canonicalize_expr<'a>(
             )
         }
 
-        ast::Expr::Str(literal) => flatten_str_literal(env, var_store, scope, literal),
+        ast::Expr::Str(literal) => flatten_str_literal(env, var_store, scope, region, literal),
 
         ast::Expr::SingleQuote(string) => {
             let mut it = string.chars().peekable();
@@ -1382,6 +1382,14 @@ pub fn canonicalize_expr<'a>(
 
             (RuntimeError(problem), Output::default())
         }
+        ast::Expr::Spread(_) => {
+            use roc_problem::can::RuntimeError::*;
+
+            let problem = UnsupportedSpread(region);
+            env.problem(Problem::RuntimeError(problem.clone()));
+
+            (RuntimeError(problem), Output::default())
+        }
         &ast::Expr::NonBase10Int {
             string,
             base,
@@ -2462,10 +2470,16 @@ fn flatten_str_literal<'a>(
     env: &mut Env<'a>,
     var_store: &mut VarStore,
     scope: &mut Scope,
+    region: Region,
     literal: &StrLiteral<'a>,
 ) -> (Expr, Output) {
     use ast::StrLiteral::*;
 
+    // A raw tab is kept as-is, but it's easy to mistake for spaces, so we suggest `\t` instead
+    if has_raw_tab(literal) {
+        env.problem(Problem::RawTabInString(region));
+    }
+
     match literal {
         PlainLine(str_slice) => (Expr::Str((*str_slice).into()), Output::default()),
         Line(segments) => flatten_str_lines(env, var_store, scope, &[segments]),
@@ -2473,6 +2487,23 @@ fn flatten_str_literal<'a>(
     }
 }
 
+pub(crate) fn has_raw_tab(literal: &StrLiteral<'_>) -> bool {
+    use ast::StrLiteral::*;
+
+    let segment_has_tab = |segment: &ast::StrSegment<'_>| match segment {
+        ast::StrSegment::Plaintext(string) => string.contains('\t'),
+        _ => false,
+    };
+
+    match literal {
+        PlainLine(string) => string.contains('\t'),
+        Line(segments) => segments.iter().any(segment_has_tab),
+        Block(lines) => lines
+            .iter()
+            .any(|segments| segments.iter().any(segment_has_tab)),
+    }
+}
+
 /// Comments, newlines, and nested interpolation are disallowed inside interpolation
 pub fn is_valid_interpolation(expr: &ast::Expr<'_>) -> bool {
     match expr {
@@ -2537,6 +2568,7 @@ pub fn is_valid_interpolation(expr: &ast::Expr<'_>) -> bool {
         | ast::Expr::OptionalFieldInRecordBuilder(_, loc_expr)
         | ast::Expr::PrecedenceConflict(PrecedenceConflict { expr: loc_expr, .. })
         | ast::Expr::UnaryOp(loc_expr, _)
+        | ast::Expr::Spread(loc_expr)
         | ast::Expr::Closure(_, loc_expr) => is_valid_interpolation(&loc_expr.value),
         ast::Expr::TupleAccess(sub_expr, _)
         | ast::Expr::ParensAround(sub_expr)
@@ -2614,6 +2646,10 @@ fn flatten_str_lines<'a>(
     let mut segments = Vec::new();
     let mut output = Output::default();
 
+    // Report every malformed segment, so they can all be fixed at once,
+    // but the string as a whole becomes the first of those errors
+    let mut first_error = None;
+
     for line in lines {
         for segment in line.iter() {
             match segment {
@@ -2625,26 +2661,28 @@ fn flatten_str_lines<'a>(
                         Some(ch) => {
                             buf.push(ch);
                         }
+                        None if (0xD800..=0xDFFF).contains(&code_pt) => {
+                            // Surrogates are reserved for UTF-16 and can't be encoded as UTF-8
+                            env.problem(Problem::UnicodeSurrogateCodePt(loc_hex_digits.region));
+
+                            first_error.get_or_insert(RuntimeError::UnicodeSurrogateCodePt(
+                                loc_hex_digits.region,
+                            ));
+                        }
                         None => {
+                            // Past the largest code point, \u(10FFFF)
                             env.problem(Problem::InvalidUnicodeCodePt(loc_hex_digits.region));
 
-                            return (
-                                Expr::RuntimeError(RuntimeError::InvalidUnicodeCodePt(
-                                    loc_hex_digits.region,
-                                )),
-                                output,
-                            );
+                            first_error.get_or_insert(RuntimeError::InvalidUnicodeCodePt(
+                                loc_hex_digits.region,
+                            ));
                         }
                     },
                     Err(_) => {
                         env.problem(Problem::InvalidHexadecimal(loc_hex_digits.region));
 
-                        return (
-                            Expr::RuntimeError(RuntimeError::InvalidHexadecimal(
-                                loc_hex_digits.region,
-                            )),
-                            output,
-                        );
+                        first_error
+                            .get_or_insert(RuntimeError::InvalidHexadecimal(loc_hex_digits.region));
                     }
                 },
                 Interpolated(loc_expr) => {
@@ -2672,10 +2710,8 @@ fn flatten_str_lines<'a>(
                     } else {
                         env.problem(Problem::InvalidInterpolation(loc_expr.region));
 
-                        return (
-                            Expr::RuntimeError(RuntimeError::InvalidInterpolation(loc_expr.region)),
-                            output,
-                        );
+                        first_error
+                            .get_or_insert(RuntimeError::InvalidInterpolation(loc_expr.region));
                     }
                 }
                 EscapedChar(escaped) => buf.push(escaped.unescape()),
@@ -2683,6 +2719,10 @@ fn flatten_str_lines<'a>(
         }
     }
 
+    if let Some(error) = first_error {
+        return (Expr::RuntimeError(error), output);
+    }
+
     if !buf.is_empty() {
         segments.push(StrSegment::Plaintext(buf.into()));
     }
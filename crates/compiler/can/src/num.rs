@@ -8,6 +8,7 @@ use roc_region::all::Region;
 pub use roc_types::num::{FloatBound, FloatWidth, IntBound, IntLitWidth, NumBound, SignDemand};
 use roc_types::subs::VarStore;
 
+use std::borrow::Cow;
 use std::str;
 
 #[inline(always)]
@@ -78,7 +79,7 @@ pub fn int_expr_from_result(
 #[inline(always)]
 pub fn float_expr_from_result(
     var_store: &mut VarStore,
-    result: Result<(&str, f64, FloatBound), (&str, FloatErrorKind)>,
+    result: Result<(Cow<str>, f64, FloatBound), (&str, FloatErrorKind)>,
     region: Region,
     env: &mut Env,
 ) -> Expr {
@@ -87,7 +88,7 @@ pub fn float_expr_from_result(
         Ok((str, float, bound)) => Expr::Float(
             var_store.fresh(),
             var_store.fresh(),
-            (*str).into(),
+            str.into(),
             float,
             bound,
         ),
@@ -149,7 +150,9 @@ pub fn finish_parsing_base(
 }
 
 #[inline(always)]
-pub fn finish_parsing_float(raw: &str) -> Result<(&str, f64, FloatBound), (&str, FloatErrorKind)> {
+pub fn finish_parsing_float(
+    raw: &str,
+) -> Result<(Cow<str>, f64, FloatBound), (&str, FloatErrorKind)> {
     let (opt_bound, raw_without_suffix) = parse_literal_suffix(raw);
 
     let bound = match opt_bound {
@@ -159,19 +162,55 @@ pub fn finish_parsing_float(raw: &str) -> Result<(&str, f64, FloatBound), (&str,
     };
 
     // Ignore underscores.
-    match raw_without_suffix.replace('_', "").parse::<f64>() {
-        Ok(float) if float.is_finite() => Ok((raw_without_suffix, float, bound)),
-        Ok(float) => {
+    let digits = raw_without_suffix.replace('_', "");
+    let is_hex = digits.trim_start_matches('-').starts_with("0x");
+    let parsed = if is_hex {
+        parse_hex_float(&digits)
+    } else {
+        digits.parse::<f64>().ok()
+    };
+
+    match parsed {
+        // Later stages read a Dec's value from this string, and they only understand decimal
+        Some(float) if float.is_finite() && is_hex => {
+            Ok((Cow::Owned(float.to_string()), float, bound))
+        }
+        Some(float) if float.is_finite() => Ok((Cow::Borrowed(raw_without_suffix), float, bound)),
+        Some(float) => {
             if float.is_sign_positive() {
                 Err((raw, FloatErrorKind::PositiveInfinity))
             } else {
                 Err((raw, FloatErrorKind::NegativeInfinity))
             }
         }
-        Err(_) => Err((raw, FloatErrorKind::Error)),
+        None => Err((raw, FloatErrorKind::Error)),
     }
 }
 
+/// Evaluate a C-style hex float like `0x1.8p3` or `-0xAp-2`, which Rust's `f64` parsing doesn't
+/// support. The mantissa is in hex, and the exponent is a power of 2 written in decimal.
+fn parse_hex_float(src: &str) -> Option<f64> {
+    let (is_negative, src) = match src.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, src),
+    };
+
+    let (mantissa, exponent) = src.strip_prefix("0x")?.split_once(['p', 'P'])?;
+    let (int_digits, frac_digits) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let mantissa = u128::from_str_radix(&format!("{int_digits}{frac_digits}"), 16).ok()?;
+    let exponent = exponent
+        .parse::<i32>()
+        .ok()?
+        .checked_sub(4 * frac_digits.len() as i32)?;
+
+    // scale in two steps, so a large mantissa with a very negative exponent doesn't underflow early
+    let half = exponent / 2;
+    let magnitude = mantissa as f64 * 2f64.powi(half) * 2f64.powi(exponent - half);
+
+    Some(if is_negative { -magnitude } else { magnitude })
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 enum ParsedWidth {
     Int(IntLitWidth),
@@ -1,6 +1,6 @@
 use crate::annotation::freshen_opaque_def;
 use crate::env::Env;
-use crate::expr::{canonicalize_expr, Expr, IntValue, Output};
+use crate::expr::{canonicalize_expr, has_raw_tab, Expr, IntValue, Output};
 use crate::num::{
     finish_parsing_base, finish_parsing_float, finish_parsing_num, FloatBound, IntBound, NumBound,
     ParsedNumResult,
@@ -550,7 +550,13 @@ pub fn canonicalize_pattern<'a>(
         },
 
         StrLiteral(literal) => match pattern_type {
-            WhenBranch => flatten_str_literal(literal),
+            WhenBranch => {
+                if has_raw_tab(literal) {
+                    env.problem(Problem::RawTabInString(region));
+                }
+
+                flatten_str_literal(literal)
+            }
             ptype => unsupported_pattern(env, ptype, region),
         },
 
@@ -17,11 +17,13 @@ mod test_can {
     use core::panic;
     use roc_can::expr::Expr::{self, *};
     use roc_can::expr::{ClosureData, IntValue, Recursive};
-    use roc_can::pattern::Pattern;
-    use roc_module::called_via::CalledVia;
+    use roc_can::pattern::{DestructType, Pattern};
+    use roc_module::called_via::{BinOp, CalledVia, UnaryOp};
     use roc_problem::can::{CycleEntry, FloatErrorKind, IntErrorKind, Problem, RuntimeError};
     use roc_region::all::{Loc, Position, Region};
+    use roc_types::num::{FloatBound, FloatWidth, IntLitWidth, NumBound};
     use roc_types::subs::Variable;
+    use roc_types::types::IndexOrField;
     use std::{f64, i64};
 
     fn assert_can_runtime_error(input: &str, expected: RuntimeError) {
@@ -94,6 +96,21 @@ mod test_can {
         }
     }
 
+    fn assert_can_num_with_width(input: &str, expected: IntValue, expected_width: IntLitWidth) {
+        let arena = Bump::new();
+        let actual_out = can_expr_with(&arena, test_home(), input);
+
+        match actual_out.loc_expr.value {
+            Expr::Num(_, _, actual, NumBound::AtLeastIntOrFloat { width, .. }) => {
+                assert_eq!(expected, actual);
+                assert_eq!(expected_width, width);
+            }
+            actual => {
+                panic!("Expected a Num with a width, but got: {:?}", actual);
+            }
+        }
+    }
+
     // NUMBER LITERALS
 
     #[test]
@@ -130,6 +147,22 @@ mod test_can {
         );
     }
 
+    #[test]
+    fn int_followed_by_letters() {
+        use roc_parse::ast::Base;
+
+        // identifiers can't start with a digit, so this is a malformed number
+        assert_can_runtime_error(
+            "2foo",
+            RuntimeError::InvalidInt(
+                IntErrorKind::InvalidDigit,
+                Base::Decimal,
+                Region::zero(),
+                "2foo".into(),
+            ),
+        );
+    }
+
     #[test]
     fn float_too_large() {
         let string = format!("{}1.0", f64::MAX);
@@ -183,6 +216,66 @@ mod test_can {
         assert_can_float("-0.0", -0.0);
     }
 
+    #[test]
+    fn minus_zero_point_zero_keeps_sign() {
+        let arena = Bump::new();
+        let actual_out = can_expr_with(&arena, test_home(), "-0.0");
+
+        match actual_out.loc_expr.value {
+            Expr::Float(_, _, string, actual, _) => {
+                assert_eq!(&*string, "-0.0");
+                // `-0.0 == 0.0`, so check the sign separately
+                assert!(actual.is_sign_negative());
+            }
+            actual => {
+                panic!("Expected a Float, but got: {:?}", actual);
+            }
+        }
+    }
+
+    #[test]
+    fn minus_zero_point_zero_exponent() {
+        assert_can_float("-0.0e0", -0.0);
+    }
+
+    #[test]
+    fn hex_float() {
+        assert_can_float("0x1.8p3", 12.0);
+    }
+
+    #[test]
+    fn hex_float_negative_exponent() {
+        assert_can_float("0xAp-2", 2.5);
+    }
+
+    #[test]
+    fn minus_hex_float() {
+        assert_can_float("-0x1.8p3", -12.0);
+    }
+
+    #[test]
+    fn hex_float_dec() {
+        // A Dec's value is read from the literal's string later on, so it has to be in decimal
+        for (input, expected_str, expected_bound) in [
+            ("0x1.8p3dec", "12", FloatBound::Exact(FloatWidth::Dec)),
+            ("0x1.8p-1", "0.75", FloatBound::None),
+            ("-0xAp-2", "-2.5", FloatBound::None),
+        ] {
+            let arena = Bump::new();
+            let actual_out = can_expr_with(&arena, test_home(), input);
+
+            match actual_out.loc_expr.value {
+                Expr::Float(_, _, actual_str, _, actual_bound) => {
+                    assert_eq!(&*actual_str, expected_str);
+                    assert_eq!(actual_bound, expected_bound);
+                }
+                actual => {
+                    panic!("Expected a Float, but got: {:?}", actual);
+                }
+            }
+        }
+    }
+
     #[test]
     fn scientific_positive() {
         assert_can_float("5e4", 50000.0);
@@ -203,6 +296,43 @@ mod test_can {
         assert_can_num(&(i64::MIN.to_string()), i64::MIN.into());
     }
 
+    // Literals too wide for an I64 are still valid; their type picks the width.
+    #[test]
+    fn num_u64_max() {
+        assert_can_num_with_width(
+            &u64::MAX.to_string(),
+            IntValue::I128(i128::from(u64::MAX).to_ne_bytes()),
+            IntLitWidth::U64,
+        );
+    }
+
+    #[test]
+    fn num_i128_max() {
+        assert_can_num_with_width(
+            &i128::MAX.to_string(),
+            IntValue::I128(i128::MAX.to_ne_bytes()),
+            IntLitWidth::I128,
+        );
+    }
+
+    #[test]
+    fn num_i128_min() {
+        assert_can_num_with_width(
+            &i128::MIN.to_string(),
+            IntValue::I128(i128::MIN.to_ne_bytes()),
+            IntLitWidth::I128,
+        );
+    }
+
+    #[test]
+    fn num_u128_max() {
+        assert_can_num_with_width(
+            &u128::MAX.to_string(),
+            IntValue::U128(u128::MAX.to_ne_bytes()),
+            IntLitWidth::U128,
+        );
+    }
+
     #[test]
     fn hex_max() {
         assert_can_int(&format!("0x{:x}", i64::MAX), i64::MAX.into());
@@ -566,6 +696,211 @@ mod test_can {
             .iter()
             .all(|problem| matches!(problem, Problem::UnusedDef(_, _))));
     }
+
+    #[test]
+    fn annotated_backpassing() {
+        let src = indoc!(
+            r"
+                andThen = \x, cb -> cb x
+
+                y : Num.Int * <- andThen 1
+
+                y
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut {
+            loc_expr, problems, ..
+        } = can_expr_with(&arena, test_home(), src);
+
+        assert!(matches!(problems[..], [Problem::DeprecatedBackpassing(_)]));
+
+        // The annotation is checked by an annotated def at the start of the callback:
+        //
+        // andThen 1 \#y -> (y : Num.Int * ; y = #y ; y)
+        let callback = match &loc_expr.value {
+            LetNonRec(_, body) => match &body.value {
+                Call(_, args, _) => &args[1].1.value,
+                other => panic!("Expected a call, but got {:?}", other),
+            },
+            other => panic!("Expected a LetNonRec, but got {:?}", other),
+        };
+
+        match callback {
+            Closure(ClosureData { loc_body, .. }) => match &loc_body.value {
+                LetNonRec(def, _) => assert!(def.annotation.is_some()),
+                other => panic!("Expected an annotated def, but got {:?}", other),
+            },
+            other => panic!("Expected a closure, but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn as_pattern_in_def_and_closure_param() {
+        let src = indoc!(
+            r"
+                (a, b) as pair = (1, 2)
+
+                f = \{ x } as r -> (x, r)
+
+                (a, b, pair, f { x: 3 })
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut {
+            loc_expr, problems, ..
+        } = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(problems, Vec::new());
+
+        let (def, body) = match &loc_expr.value {
+            LetNonRec(def, body) => (def, body),
+            other => panic!("Expected a LetNonRec, but got {:?}", other),
+        };
+
+        assert!(matches!(def.loc_pattern.value, Pattern::As(_, _)));
+
+        let closure = match &body.value {
+            LetNonRec(def, _) => &def.loc_expr.value,
+            other => panic!("Expected a LetNonRec, but got {:?}", other),
+        };
+
+        match closure {
+            Closure(ClosureData { arguments, .. }) => {
+                assert!(matches!(arguments[0].2.value, Pattern::As(_, _)))
+            }
+            other => panic!("Expected a closure, but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn record_destructure_with_renamed_fields() {
+        let src = indoc!(
+            r"
+                record = { userId: 1, point: { x: 2, y: 3 }, z: 4 }
+
+                { userId: id, point: { x, y }, z } = record
+
+                (id, x, y, z)
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut {
+            loc_expr,
+            problems,
+            interns,
+            ..
+        } = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(problems, Vec::new());
+
+        let destructs = match &loc_expr.value {
+            LetNonRec(_, body) => match &body.value {
+                LetNonRec(def, _) => match &def.loc_pattern.value {
+                    Pattern::RecordDestructure { destructs, .. } => destructs,
+                    other => panic!("Expected a record destructure, but got {:?}", other),
+                },
+                other => panic!("Expected a LetNonRec, but got {:?}", other),
+            },
+            other => panic!("Expected a LetNonRec, but got {:?}", other),
+        };
+
+        let labels: Vec<_> = destructs
+            .iter()
+            .map(|destruct| destruct.value.label.as_str())
+            .collect();
+        assert_eq!(labels, ["userId", "point", "z"]);
+
+        // `userId` is bound to the local `id`
+        match &destructs[0].value.typ {
+            DestructType::Guard(_, loc_pattern) => {
+                assert_pattern_name(&loc_pattern.value, "id", &interns)
+            }
+            other => panic!("Expected a renamed field, but got {:?}", other),
+        }
+
+        // `point` is destructured further
+        match &destructs[1].value.typ {
+            DestructType::Guard(_, loc_pattern) => {
+                assert!(matches!(
+                    loc_pattern.value,
+                    Pattern::RecordDestructure { .. }
+                ))
+            }
+            other => panic!("Expected a nested destructure, but got {:?}", other),
+        }
+
+        // `z` is punned
+        assert!(matches!(destructs[2].value.typ, DestructType::Required));
+        assert_eq!(destructs[2].value.symbol.as_str(&interns), "z");
+    }
+
+    #[test]
+    fn record_destructure_rename_does_not_bind_field_name() {
+        let src = indoc!(
+            r"
+                { userId: id } = { userId: 1 }
+
+                (id, userId)
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut { problems, .. } = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(problems.len(), 1);
+        assert!(matches!(
+            problems[0],
+            Problem::RuntimeError(RuntimeError::LookupNotInScope { .. })
+        ));
+    }
+
+    #[test]
+    fn list_spread_is_unsupported() {
+        let arena = Bump::new();
+        let CanExprOut {
+            loc_expr, problems, ..
+        } = can_expr_with(&arena, test_home(), "[1, ..[2, 3]]");
+
+        let region = Region::new(Position::new(4), Position::new(12));
+        let problem = RuntimeError::UnsupportedSpread(region);
+
+        assert_eq!(problems, vec![Problem::RuntimeError(problem.clone())]);
+
+        match loc_expr.value {
+            List { loc_elems, .. } => {
+                assert_eq!(loc_elems.len(), 2);
+                assert!(
+                    matches!(&loc_elems[1].value, Expr::RuntimeError(actual) if *actual == problem)
+                );
+            }
+            other => panic!("Expected a list, but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn lowercase_segment_in_qualified_type_is_malformed() {
+        let arena = Bump::new();
+        let src = indoc!(
+            r"
+                x : Json.decoder a
+                x = 1
+
+                x
+            "
+        );
+        let CanExprOut { problems, .. } = can_expr_with(&arena, test_home(), src);
+
+        let region = Region::new(Position::new(4), Position::new(18));
+
+        assert_eq!(
+            problems,
+            vec![Problem::RuntimeError(RuntimeError::MalformedTypeName(
+                "Json.decoder".into(),
+                region
+            ))]
+        );
+    }
+
     // LOCALS
 
     // TODO rewrite this test to check only for UnusedDef reports
@@ -801,6 +1136,182 @@ mod test_can {
         }
     }
 
+    fn get_if_condition(expr: &Expr) -> &Expr {
+        match expr {
+            Closure(ClosureData { loc_body, .. }) => match &loc_body.value {
+                If { branches, .. } => &branches[0].0.value,
+                other => panic!("closure body was not an if: {:?}", other),
+            },
+            other => panic!("Expr was not a closure: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn if_condition_with_and() {
+        let src = indoc!(
+            r#"
+                \a, b -> if a && b then 1 else 2
+            "#
+        );
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(out.problems, Vec::new());
+
+        let cond = get_if_condition(&out.loc_expr.value);
+        let args = assert_func_call(cond, "and", CalledVia::BinOp(BinOp::And), &out.interns);
+
+        assert_eq!(args.len(), 2);
+        assert_var_usage(&args[0].1.value, "a", &out.interns);
+        assert_var_usage(&args[1].1.value, "b", &out.interns);
+    }
+
+    #[test]
+    fn if_condition_with_equals_and_and() {
+        // `==` binds tighter than `&&`, so this is `(a == 1) && (b == 2)`
+        let src = indoc!(
+            r#"
+                \a, b -> if a == 1 && b == 2 then 1 else 2
+            "#
+        );
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(out.problems, Vec::new());
+
+        let cond = get_if_condition(&out.loc_expr.value);
+        let and_args = assert_func_call(cond, "and", CalledVia::BinOp(BinOp::And), &out.interns);
+        assert_eq!(and_args.len(), 2);
+
+        for (arg, name, num) in [(&and_args[0], "a", 1), (&and_args[1], "b", 2)] {
+            let eq_args = assert_func_call(
+                &arg.1.value,
+                "isEq",
+                CalledVia::BinOp(BinOp::Equals),
+                &out.interns,
+            );
+
+            assert_eq!(eq_args.len(), 2);
+            assert_var_usage(&eq_args[0].1.value, name, &out.interns);
+            assert_num_value(&eq_args[1].1.value, num);
+        }
+    }
+
+    #[test]
+    fn if_condition_with_not() {
+        let src = indoc!(
+            r#"
+                \a -> if !a then 1 else 2
+            "#
+        );
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(out.problems, Vec::new());
+
+        let cond = get_if_condition(&out.loc_expr.value);
+        let args = assert_func_call(cond, "not", CalledVia::UnaryOp(UnaryOp::Not), &out.interns);
+
+        assert_eq!(args.len(), 1);
+        assert_var_usage(&args[0].1.value, "a", &out.interns);
+    }
+
+    fn get_expect_condition(expr: &Expr) -> &Expr {
+        match expr {
+            Closure(ClosureData { loc_body, .. }) => match &loc_body.value {
+                Expect { loc_condition, .. } => &loc_condition.value,
+                other => panic!("closure body was not an expect: {:?}", other),
+            },
+            other => panic!("Expr was not a closure: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn expect_condition_with_and() {
+        let src = indoc!(
+            r#"
+                \a, b ->
+                    expect a && b
+                    1
+            "#
+        );
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(out.problems, Vec::new());
+
+        let cond = get_expect_condition(&out.loc_expr.value);
+        let args = assert_func_call(cond, "and", CalledVia::BinOp(BinOp::And), &out.interns);
+
+        assert_eq!(args.len(), 2);
+        assert_var_usage(&args[0].1.value, "a", &out.interns);
+        assert_var_usage(&args[1].1.value, "b", &out.interns);
+    }
+
+    #[test]
+    fn expect_condition_with_equals_and_and() {
+        // The condition is all of `(a == 1) && (b == 2)`, not just `a == 1`
+        let src = indoc!(
+            r#"
+                \a, b ->
+                    expect a == 1 && b == 2
+                    1
+            "#
+        );
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(out.problems, Vec::new());
+
+        let cond = get_expect_condition(&out.loc_expr.value);
+        let and_args = assert_func_call(cond, "and", CalledVia::BinOp(BinOp::And), &out.interns);
+        assert_eq!(and_args.len(), 2);
+
+        for (arg, name, num) in [(&and_args[0], "a", 1), (&and_args[1], "b", 2)] {
+            let eq_args = assert_func_call(
+                &arg.1.value,
+                "isEq",
+                CalledVia::BinOp(BinOp::Equals),
+                &out.interns,
+            );
+
+            assert_eq!(eq_args.len(), 2);
+            assert_var_usage(&eq_args[0].1.value, name, &out.interns);
+            assert_num_value(&eq_args[1].1.value, num);
+        }
+    }
+
+    #[test]
+    fn pipe_into_accessor_function() {
+        // `rec |> .field` applies the accessor to `rec`
+        let src = indoc!(
+            r#"
+                \rec -> rec |> .field
+            "#
+        );
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(out.problems, Vec::new());
+
+        let body = match &out.loc_expr.value {
+            Closure(ClosureData { loc_body, .. }) => &loc_body.value,
+            other => panic!("Expr was not a closure: {:?}", other),
+        };
+
+        match body {
+            Call(fun, args, CalledVia::BinOp(BinOp::Pizza)) => {
+                assert!(matches!(
+                    &fun.1.value,
+                    RecordAccessor(data) if data.field == IndexOrField::Field("field".into())
+                ));
+                assert_eq!(args.len(), 1);
+                assert_var_usage(&args[0].1.value, "rec", &out.interns);
+            }
+            other => panic!("Expr was not a piped call: {:?}", other),
+        }
+    }
+
     fn assert_num_value(expr: &Expr, num: usize) {
         match expr {
             Expr::Num(_, num_str, _, _) => {
@@ -1737,6 +2248,67 @@ mod test_can {
         assert_can_string(r#""x\u(101010)x""#, "x\u{101010}x");
     }
 
+    #[test]
+    fn string_with_largest_unicode_escape() {
+        assert_can_string(r#""\u(10FFFF)""#, "\u{10FFFF}");
+    }
+
+    #[test]
+    fn string_with_surrogate_unicode_escape() {
+        let arena = Bump::new();
+        let CanExprOut {
+            loc_expr, problems, ..
+        } = can_expr_with(&arena, test_home(), r#""\u(D800)""#);
+
+        let region = Region::new(Position::new(4), Position::new(8));
+
+        assert_eq!(problems, vec![Problem::UnicodeSurrogateCodePt(region)]);
+
+        match loc_expr.value {
+            Expr::RuntimeError(actual) => {
+                assert_eq!(RuntimeError::UnicodeSurrogateCodePt(region), actual);
+            }
+            actual => {
+                panic!("Expected a RuntimeError, but got: {:?}", actual);
+            }
+        }
+    }
+
+    #[test]
+    fn string_with_multiple_invalid_unicode_escapes() {
+        let arena = Bump::new();
+        let CanExprOut {
+            loc_expr, problems, ..
+        } = can_expr_with(&arena, test_home(), r#""\u(110000)x\u(D800)""#);
+
+        let first_region = Region::new(Position::new(4), Position::new(10));
+        let second_region = Region::new(Position::new(15), Position::new(19));
+
+        assert_eq!(
+            problems,
+            vec![
+                Problem::InvalidUnicodeCodePt(first_region),
+                Problem::UnicodeSurrogateCodePt(second_region),
+            ]
+        );
+
+        match loc_expr.value {
+            Expr::RuntimeError(actual) => {
+                assert_eq!(RuntimeError::InvalidUnicodeCodePt(first_region), actual);
+            }
+            actual => {
+                panic!("Expected a RuntimeError, but got: {:?}", actual);
+            }
+        }
+    }
+
+    #[test]
+    fn string_with_byte_and_null_escapes() {
+        assert_can_string(r#""\x41""#, "A");
+        assert_can_string(r#""x\x7fx\x0a""#, "x\u{7f}x\n");
+        assert_can_string(r#""a\0b""#, "a\0b");
+    }
+
     #[test]
     fn block_string() {
         assert_can_string(
@@ -1757,6 +2329,70 @@ mod test_can {
         );
     }
 
+    #[test]
+    fn string_with_raw_tab() {
+        // Kept as-is, but with a warning suggesting `\t` instead
+        assert_can_string("\"a\tb\"", "a\tb");
+
+        let src = indoc!(
+            "
+                x = \"a\tb\"
+
+                x
+            "
+        );
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(
+            out.problems,
+            vec![Problem::RawTabInString(Region::new(
+                Position::new(4),
+                Position::new(9)
+            ))]
+        );
+    }
+
+    #[test]
+    fn string_with_raw_carriage_return() {
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), "\"a\rb\"");
+
+        assert!(matches!(&out.loc_expr.value, Expr::Str(string) if &**string == "a\rb"));
+        assert_eq!(out.problems, Vec::new());
+    }
+
+    #[test]
+    fn block_string_with_raw_tab() {
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), "\"\"\"a\n\tb\"\"\"");
+
+        assert!(matches!(&out.loc_expr.value, Expr::Str(string) if &**string == "a\n\tb"));
+        assert!(matches!(out.problems[..], [Problem::RawTabInString(_)]));
+    }
+
+    #[test]
+    fn string_pattern_with_raw_tab() {
+        let src = indoc!(
+            "
+                \\x ->
+                    when x is
+                        \"a\tb\" -> 1
+                        _ -> 0
+            "
+        );
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(
+            out.problems,
+            vec![Problem::RawTabInString(Region::new(
+                Position::new(28),
+                Position::new(33)
+            ))]
+        );
+    }
+
     //     #[test]
     //     fn string_with_too_large_unicode_escape() {
     //         // Should be too big - max size should be 10FFFF.
@@ -1852,7 +2488,5 @@ mod test_can {
     //
     // TODO test hex/oct/binary conversion to numbers
     //
-    // TODO test for \t \r and \n in string literals *outside* unicode escape sequence!
-    //
     // TODO test for multiline block string literals in pattern matches
 }
@@ -149,9 +149,12 @@ impl<'a> Formattable for TypeAnnotation<'a> {
             }
 
             Wildcard | Inferred | BoundVariable(_) | Malformed(_) => false,
-            Function(args, result) => {
+            Function(args, result, effect) => {
                 result.value.is_multiline()
                     || args.iter().any(|loc_arg| loc_arg.value.is_multiline())
+                    || effect
+                        .iter()
+                        .any(|loc_effect| loc_effect.value.is_multiline())
             }
             Apply(_, _, args) => args.iter().any(|loc_arg| loc_arg.value.is_multiline()),
             As(lhs, _, _) => lhs.value.is_multiline(),
@@ -195,7 +198,7 @@ impl<'a> Formattable for TypeAnnotation<'a> {
         let self_is_multiline = self.is_multiline();
 
         match self {
-            Function(args, ret) => {
+            Function(args, ret, effect) => {
                 let needs_parens = parens != Parens::NotNeeded;
 
                 buf.indent(indent);
@@ -242,6 +245,19 @@ impl<'a> Formattable for TypeAnnotation<'a> {
                 ret.value
                     .format_with_options(buf, Parens::InFunctionType, Newlines::No, indent);
 
+                if let Some(effect) = effect {
+                    buf.spaces(1);
+                    buf.push('|');
+                    buf.spaces(1);
+
+                    effect.value.format_with_options(
+                        buf,
+                        Parens::InFunctionType,
+                        Newlines::No,
+                        indent,
+                    );
+                }
+
                 if needs_parens {
                     buf.push(')')
                 }
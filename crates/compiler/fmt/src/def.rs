@@ -5,9 +5,10 @@ use crate::pattern::fmt_pattern;
 use crate::spaces::{fmt_default_newline, fmt_default_spaces, fmt_spaces, INDENT};
 use crate::Buf;
 use roc_parse::ast::{
-    AbilityMember, Defs, Expr, ExtractSpaces, ImportAlias, ImportAsKeyword, ImportExposingKeyword,
-    ImportedModuleName, IngestedFileAnnotation, IngestedFileImport, ModuleImport,
-    ModuleImportParams, Pattern, Spaces, StrLiteral, TypeAnnotation, TypeDef, TypeHeader, ValueDef,
+    AbilityMember, Defs, Expr, ExtractSpaces, ImportAlias, ImportAsKeyword, ImportExposedName,
+    ImportExposingKeyword, ImportedModuleName, IngestedFileAnnotation, IngestedFileImport,
+    ModuleImport, ModuleImportParams, Pattern, Spaces, StrLiteral, TypeAnnotation, TypeDef,
+    TypeHeader, ValueDef,
 };
 use roc_parse::header::Keyword;
 use roc_region::all::Loc;
@@ -26,6 +27,11 @@ impl<'a> Formattable for Defs<'a> {
         _newlines: Newlines,
         indent: u16,
     ) {
+        if self.tags.is_empty() {
+            fmt_spaces(buf, self.spaces.iter(), indent);
+            return;
+        }
+
         let mut prev_spaces = true;
 
         for (index, def) in self.defs().enumerate() {
@@ -345,6 +351,25 @@ impl<'a> Formattable for ImportAlias<'a> {
     }
 }
 
+impl<'a> Formattable for ImportExposedName<'a> {
+    fn is_multiline(&self) -> bool {
+        self.alias.is_multiline()
+    }
+
+    fn format_with_options(
+        &self,
+        buf: &mut Buf,
+        _parens: Parens,
+        _newlines: Newlines,
+        indent: u16,
+    ) {
+        let Self { name, alias } = self;
+
+        name.format(buf, indent);
+        alias.format(buf, indent);
+    }
+}
+
 impl Formattable for ImportAsKeyword {
     fn is_multiline(&self) -> bool {
         false
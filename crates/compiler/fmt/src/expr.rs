@@ -9,8 +9,8 @@ use crate::spaces::{
 use crate::Buf;
 use roc_module::called_via::{self, BinOp};
 use roc_parse::ast::{
-    is_expr_suffixed, AssignedField, Base, Collection, CommentOrNewline, Expr, ExtractSpaces,
-    Pattern, TryTarget, WhenBranch,
+    is_expr_suffixed, AssignedField, BackpassingPatterns, Base, Collection, CommentOrNewline, Expr,
+    ExtractSpaces, Pattern, TryTarget, WhenBranch,
 };
 use roc_parse::ast::{StrLiteral, StrSegment};
 use roc_parse::ident::Accessor;
@@ -88,6 +88,7 @@ impl<'a> Formattable for Expr<'a> {
             }
 
             UnaryOp(loc_subexpr, _)
+            | Spread(loc_subexpr)
             | PrecedenceConflict(roc_parse::ast::PrecedenceConflict {
                 expr: loc_subexpr, ..
             })
@@ -104,11 +105,13 @@ impl<'a> Formattable for Expr<'a> {
                         .iter()
                         .any(|loc_pattern| loc_pattern.is_multiline())
             }
-            Backpassing(loc_patterns, loc_body, loc_ret) => {
+            Backpassing(lhs, loc_body, loc_ret) => {
                 // check the body first because it's more likely to be multiline
                 loc_body.is_multiline()
                     || loc_ret.is_multiline()
-                    || loc_patterns
+                    || lhs.ann.map_or(false, |loc_ann| loc_ann.is_multiline())
+                    || lhs
+                        .patterns
                         .iter()
                         .any(|loc_pattern| loc_pattern.is_multiline())
             }
@@ -389,8 +392,8 @@ impl<'a> Formattable for Expr<'a> {
             Closure(loc_patterns, loc_ret) => {
                 fmt_closure(buf, loc_patterns, loc_ret, indent);
             }
-            Backpassing(loc_patterns, loc_body, loc_ret) => {
-                fmt_backpassing(buf, loc_patterns, loc_body, loc_ret, indent);
+            Backpassing(lhs, loc_body, loc_ret) => {
+                fmt_backpassing(buf, lhs, loc_body, loc_ret, indent);
             }
             Defs(defs, ret) => {
                 {
@@ -507,6 +510,11 @@ impl<'a> Formattable for Expr<'a> {
                     buf.push(')');
                 }
             }
+            Spread(sub_expr) => {
+                buf.indent(indent);
+                buf.push_str("..");
+                sub_expr.format_with_options(buf, Parens::InApply, newlines, indent);
+            }
             AccessorFunction(key) => {
                 buf.indent(indent);
                 buf.push('.');
@@ -661,6 +669,10 @@ fn format_str_segment(seg: &StrSegment, buf: &mut Buf, indent: u16) {
         EscapedChar(escaped) => {
             buf.push('\\');
             buf.push(escaped.to_parsed_char());
+
+            if let roc_parse::ast::EscapedChar::Byte(byte) = escaped {
+                buf.push_str(&format!("{byte:02X}")); // e.g. "41" in "\x41"
+            }
         }
         Interpolated(loc_expr) => {
             buf.push_str("$(");
@@ -974,7 +986,18 @@ fn fmt_when<'a>(
         if let Some(guard_expr) = &branch.guard {
             buf.push_str(" if");
             buf.spaces(1);
-            guard_expr.format_with_options(buf, Parens::NotNeeded, Newlines::Yes, indent + INDENT);
+
+            // Without its parens, a `when` in the guard would take this branch's `->` as its own
+            let guard_parens = match guard_expr.value.extract_spaces().item {
+                Expr::ParensAround(sub_expr)
+                    if matches!(sub_expr.extract_spaces().item, Expr::When(..)) =>
+                {
+                    Parens::InOperator
+                }
+                _ => Parens::NotNeeded,
+            };
+
+            guard_expr.format_with_options(buf, guard_parens, Newlines::Yes, indent + 2 * INDENT);
         }
 
         buf.push_str(" ->");
@@ -1132,6 +1155,7 @@ fn fmt_if<'a>(
 
                         _ => {
                             expr_below.format(buf, return_indent);
+                            buf.newline();
                         }
                     }
                 }
@@ -1323,13 +1347,14 @@ fn fmt_closure<'a>(
 
 fn fmt_backpassing<'a>(
     buf: &mut Buf,
-    loc_patterns: &'a [Loc<Pattern<'a>>],
+    lhs: &'a BackpassingPatterns<'a>,
     loc_body: &'a Loc<Expr<'a>>,
     loc_ret: &'a Loc<Expr<'a>>,
     indent: u16,
 ) {
     use self::Expr::*;
 
+    let loc_patterns = lhs.patterns;
     let arguments_are_multiline = loc_patterns
         .iter()
         .any(|loc_pattern| loc_pattern.is_multiline());
@@ -1363,6 +1388,13 @@ fn fmt_backpassing<'a>(
         }
     }
 
+    if let Some(loc_ann) = &lhs.ann {
+        buf.spaces(1);
+        buf.push(':');
+        buf.spaces(1);
+        loc_ann.format_with_options(buf, Parens::NotNeeded, Newlines::No, indent + INDENT);
+    }
+
     if arguments_are_multiline {
         buf.newline();
         buf.indent(indent);
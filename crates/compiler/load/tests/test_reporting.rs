@@ -19,6 +19,7 @@ mod test_reporting {
     use roc_parse::header::parse_header;
     use roc_parse::state::State;
     use roc_parse::test_helpers::parse_expr_with;
+    use roc_problem::can::{Problem, RuntimeError};
     use roc_problem::Severity;
     use roc_region::all::LineInfo;
     use roc_reporting::report::{
@@ -3390,6 +3391,55 @@ mod test_reporting {
     "###
     );
 
+    test_report!(
+        unparenthesized_if_arg,
+        indoc!(
+            r"
+            f if c then a else b
+            "
+        ),
+        @r###"
+    ── MISSING PARENTHESES in tmp/unparenthesized_if_arg/Test.roc ──────────────────
+
+    I am partway through parsing an expression, but I got stuck on this
+    `if`:
+
+    1│  app "test" provides [main] to "./platform"
+    2│
+    3│  main =
+    4│      f if c then a else b
+              ^
+
+    An `if` expression can only be passed to a function when it is wrapped
+    in parentheses, like f (if cond then a else b).
+    "###
+    );
+
+    test_report!(
+        unparenthesized_when_arg,
+        indoc!(
+            r"
+            f x when x is
+                _ -> 1
+            "
+        ),
+        @r###"
+    ── MISSING PARENTHESES in tmp/unparenthesized_when_arg/Test.roc ────────────────
+
+    I am partway through parsing an expression, but I got stuck on this
+    `when`:
+
+    1│  app "test" provides [main] to "./platform"
+    2│
+    3│  main =
+    4│      f x when x is
+                ^
+
+    A `when` expression can only be passed to a function when it is wrapped
+    in parentheses, like f (when x is ...).
+    "###
+    );
+
     test_report!(
         two_different_cons,
         indoc!(
@@ -3628,6 +3678,30 @@ mod test_reporting {
             "
         ),
         @r"
+    ── INVALID DIGIT in tmp/integer_malformed/Test.roc ─────────────────────────────
+
+    This hex integer literal contains an invalid digit:
+
+    6│      hex = 0xZZZ
+                    ^
+
+    Hexadecimal (base-16) integer literals can only contain the digits
+    0-9, a-f and A-F, or have an integer suffix.
+
+    Tip: Learn more about number literals at TODO
+    "
+    );
+
+    test_report!(
+        decimal_integer_invalid_digit,
+        indoc!(
+            r"
+            dec = 100A
+
+            dec
+            "
+        ),
+        @r"
     ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
 
     This integer literal contains an invalid digit:
@@ -3639,37 +3713,73 @@ mod test_reporting {
     0-9, or have an integer suffix.
 
     Tip: Learn more about number literals at TODO
+    "
+    );
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    test_report!(
+        hex_integer_invalid_digit,
+        indoc!(
+            r"
+            hex = 0xZZ
+
+            hex
+            "
+        ),
+        @r"
+    ── INVALID DIGIT in tmp/hex_integer_invalid_digit/Test.roc ─────────────────────
 
     This hex integer literal contains an invalid digit:
 
-    6│      hex = 0xZZZ
-                  ^^^^^
+    4│      hex = 0xZZ
+                    ^
 
     Hexadecimal (base-16) integer literals can only contain the digits
     0-9, a-f and A-F, or have an integer suffix.
 
     Tip: Learn more about number literals at TODO
+    "
+    );
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    test_report!(
+        octal_integer_invalid_digit,
+        indoc!(
+            r"
+            oct = 0o99
+
+            oct
+            "
+        ),
+        @r"
+    ── INVALID DIGIT in tmp/octal_integer_invalid_digit/Test.roc ───────────────────
 
     This octal integer literal contains an invalid digit:
 
-    8│      oct = 0o9
-                  ^^^
+    4│      oct = 0o99
+                    ^
 
     Octal (base-8) integer literals can only contain the digits
     0-7, or have an integer suffix.
 
     Tip: Learn more about number literals at TODO
+    "
+    );
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    test_report!(
+        binary_integer_invalid_digit,
+        indoc!(
+            r"
+            bin = 0b12
+
+            bin
+            "
+        ),
+        @r"
+    ── INVALID DIGIT in tmp/binary_integer_invalid_digit/Test.roc ──────────────────
 
     This binary integer literal contains an invalid digit:
 
-    10│      bin = 0b2
-                   ^^^
+    4│      bin = 0b12
+                     ^
 
     Binary (base-2) integer literals can only contain the digits
     0 and 1, or have an integer suffix.
@@ -3756,6 +3866,28 @@ mod test_reporting {
     "
     );
 
+    test_report!(
+        hex_float_missing_exponent,
+        indoc!(
+            r"
+            x = 0x1.8
+
+            x
+            "
+        ),
+        @r"
+    ── MISSING EXPONENT in tmp/hex_float_missing_exponent/Test.roc ─────────────────
+
+    This hexadecimal float literal is missing its exponent:
+
+    4│      x = 0x1.8
+                ^^^^^
+
+    Hexadecimal floats need a binary exponent after a p, like 0x1.8p3,
+    which is 1.5 times 2 to the power of 3, or 0x1.8p0 for just 1.5.
+    "
+    );
+
     test_report!(
         invalid_record_update,
         indoc!(
@@ -4269,12 +4401,13 @@ mod test_reporting {
         @r"
     ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
 
-    I am trying to parse a record field access here:
+    I encountered a dot with nothing after it:
 
     4│      foo.bar.
-                    ^
+                   ^
 
-    So I expect to see a lowercase letter next, like .name or .height.
+    I was expecting a field name after the ., like user.name, or a tuple
+    index, like pair.0.
     "
     );
 
@@ -4305,10 +4438,6 @@ mod test_reporting {
     "#
     );
 
-    // NOTE: VERY BAD ERROR MESSAGE
-    //
-    // looks like `x y` are considered argument to the add, even though they are
-    // on a lower indentation level
     test_report!(
         double_equals_in_def,
         indoc!(
@@ -4322,12 +4451,9 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── STATEMENT AFTER EXPRESSION in tmp/double_equals_in_def/Test.roc ─────────────
+    ── DOUBLE EQUALS IN DEFINITION in tmp/double_equals_in_def/Test.roc ────────────
 
-    I just finished parsing an expression with a series of definitions,
-
-    and this line is indented as if it's intended to be part of that
-    expression:
+    This looks like a definition, but it uses == instead of =:
 
     1│  app "test" provides [main] to "./platform"
     2│
@@ -4335,19 +4461,37 @@ mod test_reporting {
     4│      x = 3
     5│      y =
     6│          x == 5
-    7│          Num.add 1 2
-                ^
+                  ^^
+
+    The == operator compares two values, so I read this as the final
+    expression, but there is more code after it. To define a value, use a
+    single =, like x = 5.
+    "###
+    );
+
+    test_report!(
+        double_equals_def,
+        indoc!(
+            r"
+            count == 5
 
-    However, I already saw the final expression in that series of
-    definitions.
+            count + 1
+            "
+        ),
+        @r###"
+    ── DOUBLE EQUALS IN DEFINITION in tmp/double_equals_def/Test.roc ───────────────
+
+    This looks like a definition, but it uses == instead of =:
+
+    1│  app "test" provides [main] to "./platform"
+    2│
+    3│  main =
+    4│      count == 5
+                  ^^
 
-    Tip: An expression like `4`, `"hello"`, or `functionCall MyThing` is
-    like `return 4` in other programming languages. To me, it seems like
-    you did `return 4` followed by more code in the lines after, that code
-    would never be executed!
-    
-    Tip: If you are working with `Task`, this error can happen if you
-    forgot a `!` somewhere.
+    The == operator compares two values, so I read this as the final
+    expression, but there is more code after it. To define a value, use a
+    single =, like x = 5.
     "###
     );
 
@@ -4437,6 +4581,47 @@ mod test_reporting {
     "
     );
 
+    test_report!(
+        tag_union_trailing_pipe,
+        indoc!(
+            r"
+            f : [| Good | Bad |]
+            "
+        ),
+        @r"
+    ── MISSING TAG AFTER PIPE in tmp/tag_union_trailing_pipe/Test.roc ──────────────
+
+    I am partway through parsing a tag union type, but I got stuck here:
+
+    4│      f : [| Good | Bad |]
+                              ^
+
+    I was expecting to see a tag after this |. Every | comes before a tag,
+    so there is no trailing one, like in [| Red | Green].
+    "
+    );
+
+    test_report!(
+        tag_union_mixed_separators,
+        indoc!(
+            r"
+            f : [Good | Bad]
+            "
+        ),
+        @r"
+    ── MIXED TAG SEPARATORS in tmp/tag_union_mixed_separators/Test.roc ─────────────
+
+    I am partway through parsing a tag union type, but I got stuck here:
+
+    4│      f : [Good | Bad]
+                      ^
+
+    The tags of a tag union are separated either by commas, like
+    [Red, Green], or by a | before each one, like [| Red | Green]. Try
+    using just one of those!
+    "
+    );
+
     test_report!(
         record_type_open,
         indoc!(
@@ -4578,6 +4763,22 @@ mod test_reporting {
     "###
     );
 
+    test_report!(
+        string_with_raw_tab,
+        "x = \"a\tb\"\n\nx",
+        @r###"
+    ── TAB IN STRING in /code/proj/Main.roc ────────────────────────────────────────
+
+    This string contains a tab character:
+
+    4│      x = "a	b"
+                ^^^^^
+
+    Tabs are easy to mistake for spaces, so it's clearer to write them as
+    \t instead.
+    "###
+    );
+
     test_report!(
         comment_with_control_character,
         "# comment with a \x07 char\n",
@@ -4788,38 +4989,33 @@ mod test_reporting {
     "
     );
 
-    // TODO investigate this test. It was disabled in https://github.com/roc-lang/roc/pull/6634
-    // as the way Defs without final expressions are handled. The changes probably shouldn't have
-    // changed this error report. The exact same test_syntax test for this has not changed, so
-    // we know the parser is parsing the same thing. Therefore the way the AST is desugared must be
-    // the cause of the change in error report.
-    // test_report!(
-    //     def_missing_final_expression,
-    //     indoc!(
-    //         r"
-    //         f : Foo.foo
-    //         "
-    //     ),
-    //     @r#"
-    // ── MISSING FINAL EXPRESSION in tmp/def_missing_final_expression/Test.roc ───────
+    test_report!(
+        def_missing_final_expression,
+        indoc!(
+            r"
+            f : Foo.foo
+            "
+        ),
+        @r#"
+    ── MISSING FINAL EXPRESSION in tmp/def_missing_final_expression/Test.roc ───────
 
-    // I am partway through parsing a definition, but I got stuck here:
+    I am partway through parsing a definition, but I got stuck here:
 
-    // 1│  app "test" provides [main] to "./platform"
-    // 2│
-    // 3│  main =
-    // 4│      f : Foo.foo
-    //                    ^
+    1│  app "test" provides [main] to "./platform"
+    2│
+    3│  main =
+    4│      f : Foo.foo
+                       ^
 
-    // This definition is missing a final expression. A nested definition
-    // must be followed by either another definition, or an expression
+    This definition is missing a final expression. A nested definition
+    must be followed by either another definition, or an expression
 
-    //     x = 4
-    //     y = 2
+        x = 4
+        y = 2
 
-    //     x + y
-    // "#
-    // );
+        x + y
+    "#
+    );
 
     test_report!(
         expression_indentation_end,
@@ -4829,9 +5025,9 @@ mod test_reporting {
             "
         ),
         @r#"
-    ── INDENT ENDS AFTER EXPRESSION in tmp/expression_indentation_end/Test.roc ─────
+    ── MISSING FINAL EXPRESSION in tmp/expression_indentation_end/Test.roc ─────────
 
-    I am partway through parsing an expression, but I got stuck here:
+    I am partway through parsing a definition, but I got stuck here:
 
     1│  app "test" provides [main] to "./platform"
     2│
@@ -4839,8 +5035,44 @@ mod test_reporting {
     4│      f = Foo.foo
                        ^
 
-    Looks like the indentation ends prematurely here. Did you mean to have
-    another expression after this line?
+    This definition is missing a final expression. A nested definition
+    must be followed by either another definition, or an expression
+
+        x = 4
+        y = 2
+
+        x + y
+    "#
+    );
+
+    test_report!(
+        nested_def_missing_final_expression,
+        indoc!(
+            r"
+            f =
+                a = 1
+            f
+            "
+        ),
+        @r#"
+    ── MISSING FINAL EXPRESSION in ...nested_def_missing_final_expression/Test.roc ─
+
+    I am partway through parsing a definition, but I got stuck here:
+
+    1│  app "test" provides [main] to "./platform"
+    2│
+    3│  main =
+    4│      f =
+    5│          a = 1
+                     ^
+
+    This definition is missing a final expression. A nested definition
+    must be followed by either another definition, or an expression
+
+        x = 4
+        y = 2
+
+        x + y
     "#
     );
 
@@ -4889,6 +5121,29 @@ mod test_reporting {
     "
     );
 
+    test_report!(
+        function_type_missing_effect,
+        indoc!(
+            r"
+            f : Str -> {} |
+            f = \_ -> {}
+
+            f
+            "
+        ),
+        @r"
+    ── MISSING EFFECT in tmp/function_type_missing_effect/Test.roc ─────────────────
+
+    I just saw a | after this function's return type, so I was expecting
+    an effect next:
+
+    4│      f : Str -> {} |
+                           ^
+
+    An effect goes at the end of a function type, like Str -> {} | Effect.
+    "
+    );
+
     test_report!(
         type_argument_no_arrow,
         indoc!(
@@ -4934,6 +5189,28 @@ mod test_reporting {
     "
     );
 
+    test_report!(
+        function_type_leading_comma,
+        indoc!(
+            r"
+            f : , I64 -> I64
+            f = \_ -> 0
+
+            f
+            "
+        ),
+        @r"
+    ── UNFINISHED TYPE in tmp/function_type_leading_comma/Test.roc ─────────────────
+
+    I just started parsing a type, but I got stuck here:
+
+    4│      f : , I64 -> I64
+                ^
+
+    I am expecting a type next, like Bool or List a.
+    "
+    );
+
     test_report!(
         unfinished_import,
         indoc!(
@@ -5105,6 +5382,28 @@ mod test_reporting {
         import Svg exposing [Path, arc, rx]
     "###);
 
+    test_report!(
+        exposed_alias_with_mismatched_case,
+        indoc!(
+            r"
+            import Json exposing [map as JsonMap]
+            "
+        ),
+        @r"
+    ── WEIRD EXPOSED ALIAS in tmp/exposed_alias_with_mismatched_case/Test.roc ──────
+
+    This import renames an exposed name to one with different
+    capitalization:
+
+    4│      import Json exposing [map as JsonMap]
+                                         ^^^^^^^
+
+    Values and functions can only be renamed to lowercase names, like
+    map as jsonMap, and types to uppercase names, like
+    Decoder as JsonDecoder.
+    "
+    );
+
     test_report!(
         unfinished_ingested_file_name,
         indoc!(
@@ -5239,11 +5538,52 @@ mod test_reporting {
     );
 
     test_report!(
-        empty_or_pattern,
+        if_guard_with_unparenthesized_when,
         indoc!(
             r"
-            when Just 4 is
-                Just 4 | ->
+            when 5 is
+                n if when n is
+                    0 -> Bool.false
+                    _ -> Bool.true -> 1
+
+                _ -> 2
+            "
+        ),
+        @r"
+    ── UNEXPECTED ARROW in tmp/if_guard_with_unparenthesized_when/Test.roc ─────────
+
+    I am parsing a `when` expression right now, but this arrow is confusing
+    me:
+
+    5│          n if when n is
+    6│              0 -> Bool.false
+    7│              _ -> Bool.true -> 1
+                                   ^^
+
+    It makes sense to see arrows around here, so I suspect it is something
+    earlier. Maybe this pattern is indented a bit farther from the
+    previous patterns?
+
+    Note: Here is an example of a valid `when` expression for reference.
+
+        when List.first plants is
+          Ok n ->
+            n
+
+          Err _ ->
+            200
+
+    Notice the indentation. All patterns are aligned, and each branch is
+    indented a bit more than the corresponding pattern. That is important!
+    "
+    );
+
+    test_report!(
+        empty_or_pattern,
+        indoc!(
+            r"
+            when Just 4 is
+                Just 4 | ->
                     4
 
                 _ ->
@@ -5337,6 +5677,41 @@ mod test_reporting {
     "
     );
 
+    test_report!(
+        when_without_branches,
+        indoc!(
+            r"
+            x =
+                when 4 is
+
+            x
+            "
+        ),
+        @r"
+    ── MISSING WHEN BRANCHES in tmp/when_without_branches/Test.roc ─────────────────
+
+    This `when` expression doesn't have any branches:
+
+    5│          when 4 is
+                ^^^^^^^^^
+
+    I was expecting to see at least one branch after the `is`, indented a
+    bit more than the line it is on.
+
+    Note: Here is an example of a valid `when` expression for reference.
+
+        when List.first plants is
+          Ok n ->
+            n
+
+          Err _ ->
+            200
+
+    Notice the indentation. All patterns are aligned, and each branch is
+    indented a bit more than the corresponding pattern. That is important!
+    "
+    );
+
     test_report!(
         lambda_double_comma,
         indoc!(
@@ -5379,30 +5754,6 @@ mod test_reporting {
     "
     );
 
-    // this should get better with time
-    // TODO this formerly gave
-    //
-    //                ── UNFINISHED WHEN ─────────────────────────────────────────────────────────────
-    //
-    //                I was partway through parsing a `when` expression, but I got stuck here:
-    //
-    //                3│    _ -> 2
-    //                        ^
-    //
-    //                I suspect this is a pattern that is not indented enough? (by 2 spaces)
-    //
-    // but that requires parsing the next pattern blindly, irrespective of indentation. Can
-    // we find an efficient solution that doesn't require parsing an extra pattern for
-    // every `when`, i.e. we want a good error message for the test case above, but for
-    // a valid `when`, we don't want to do extra work, e.g. here
-    //
-    //  x
-    //      when x is
-    //          n -> n
-    //
-    //  4
-    //
-    // We don't want to parse the `4` and say it's an outdented pattern!
     test_report!(
         when_outdented_branch,
         indoc!(
@@ -5413,26 +5764,94 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── UNKNOWN OPERATOR in tmp/when_outdented_branch/Test.roc ──────────────────────
+    ── UNFINISHED WHEN in tmp/when_outdented_branch/Test.roc ───────────────────────
 
-    This looks like an operator, but it's not one I recognize!
+    I was partway through parsing a `when` expression, but I got stuck here:
 
-    1│  app "test" provides [main] to "./platform"
-    2│
-    3│  main =
     4│      when 4 is
     5│          5 -> 2
     6│       2 -> 2
-               ^^
+             ^
 
-    Looks like you are trying to define a function. 
+    I suspect this is a pattern that is not indented enough? (by 3 spaces)
 
-    In Roc, functions are always written as a lambda, like 
+    Note: Here is an example of a valid `when` expression for reference.
 
-        increment = \n -> n + 1
+        when List.first plants is
+          Ok n ->
+            n
+
+          Err _ ->
+            200
+
+    Notice the indentation. All patterns are aligned, and each branch is
+    indented a bit more than the corresponding pattern. That is important!
+    "###
+    );
+
+    test_report!(
+        when_outdented_is,
+        indoc!(
+            r"
+            x =
+                when
+                    longExpr
+                        |> transform
+              is
+                    _ -> 1
+            x
+            "
+        ),
+        @r###"
+    ── UNFINISHED WHEN in tmp/when_outdented_is/Test.roc ───────────────────────────
+
+    I was partway through parsing a `when` expression, but I got stuck here:
+
+    5│          when
+    6│              longExpr
+    7│                  |> transform
+    8│        is
+              ^
+
+    I suspect this `is` keyword is not indented enough? It should be
+    indented at least as much as the line containing the `when`.
+
+    Note: Here is an example of a valid `when` expression for reference.
+
+        when List.first plants is
+          Ok n ->
+            n
+
+          Err _ ->
+            200
+
+    Notice the indentation. All patterns are aligned, and each branch is
+    indented a bit more than the corresponding pattern. That is important!
     "###
     );
 
+    test_report!(
+        when_double_bar,
+        indoc!(
+            r"
+            when x is
+                A || B -> 1
+            "
+        ),
+        @r"
+    ── DOUBLE BAR in tmp/when_double_bar/Test.roc ──────────────────────────────────
+
+    I am partway through parsing the patterns of a `when` branch, but I got
+    stuck here:
+
+    4│      when x is
+    5│          A || B -> 1
+                  ^^
+
+    Alternative patterns are separated by a single |, like A | B -> 1.
+    "
+    );
+
     test_report!(
         when_over_indented_underscore,
         indoc!(
@@ -5553,6 +5972,25 @@ mod test_reporting {
     "
     );
 
+    test_report!(
+        if_missing_condition,
+        indoc!(
+            r"
+            if then x else y
+            "
+        ),
+        @r"
+    ── UNFINISHED IF in tmp/if_missing_condition/Test.roc ──────────────────────────
+
+    I was partway through parsing an `if` expression, but I got stuck here:
+
+    4│      if then x else y
+               ^
+
+    I was expecting to see a condition next, like if x > 0 then.
+    "
+    );
+
     test_report!(
         list_double_comma,
         indoc!(
@@ -5561,18 +5999,106 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED LIST in tmp/list_double_comma/Test.roc ───────────────────────────
+    ── UNEXPECTED COMMA in tmp/list_double_comma/Test.roc ──────────────────────────
 
-    I am partway through started parsing a list, but I got stuck here:
+    I am partway through parsing a list, but I got stuck on this comma:
 
     4│      [1, 2, , 3]
                    ^
 
-    I was expecting to see a list entry before this comma, so try adding a
-    list entry and see if that helps?
+    There is no list entry before this comma, so try removing it. Commas
+    go after each list entry, and a trailing comma is fine, like [1, 2,].
+    "
+    );
+
+    test_report!(
+        list_leading_comma,
+        indoc!(
+            r"
+            [, 1, 2]
+            "
+        ),
+        @r"
+    ── UNEXPECTED COMMA in tmp/list_leading_comma/Test.roc ─────────────────────────
+
+    I am partway through parsing a list, but I got stuck on this comma:
+
+    4│      [, 1, 2]
+             ^
+
+    There is no list entry before this comma, so try removing it. Commas
+    go after each list entry, and a trailing comma is fine, like [1, 2,].
     "
     );
 
+    test_report!(
+        record_leading_comma,
+        indoc!(
+            r"
+            { , x }
+            "
+        ),
+        @r###"
+    ── UNEXPECTED COMMA in tmp/record_leading_comma/Test.roc ───────────────────────
+
+    I am partway through parsing a record, but I got stuck on this comma:
+
+    1│  app "test" provides [main] to "./platform"
+    2│
+    3│  main =
+    4│      { , x }
+              ^
+
+    There is no field before this comma, so try removing it. Commas go
+    after each field, and a trailing comma is fine, like { x: 1, }.
+    "###
+    );
+
+    test_report!(
+        record_double_ampersand,
+        indoc!(
+            r"
+            { r && x: 1 }
+            "
+        ),
+        @r###"
+    ── DOUBLE AMPERSAND in tmp/record_double_ampersand/Test.roc ────────────────────
+
+    I am partway through parsing a record update, but I got stuck here:
+
+    1│  app "test" provides [main] to "./platform"
+    2│
+    3│  main =
+    4│      { r && x: 1 }
+                ^^
+
+    A record update uses a single &, like { user & name: "Sam" }.
+    "###
+    );
+
+    test_report!(
+        record_parenthesized_field_name,
+        indoc!(
+            r"
+            { (x): 1 }
+            "
+        ),
+        @r###"
+    ── WEIRD FIELD NAME in tmp/record_parenthesized_field_name/Test.roc ────────────
+
+    I am partway through parsing a record, but I got stuck here:
+
+    1│  app "test" provides [main] to "./platform"
+    2│
+    3│  main =
+    4│      { (x): 1 }
+              ^
+
+    Record field names must be plain lowercase names, like { x: 1 }. They
+    can't be strings or computed from an expression.
+    "###
+    );
+
     test_report!(
         list_without_end,
         indoc!(
@@ -5583,42 +6109,206 @@ mod test_reporting {
         @r"
     ── UNFINISHED LIST in tmp/list_without_end/Test.roc ────────────────────────────
 
-    I am partway through started parsing a list, but I got stuck here:
+    I am partway through started parsing a list, but I got stuck here:
+
+    4│      [1, 2,
+    5│
+    6│
+        ^
+
+    I was expecting to see a closing square bracket before this, so try
+    adding a ] and see if that helps?
+
+    Note: When I get stuck like this, it usually means that there is a
+    missing parenthesis or bracket somewhere earlier. It could also be a
+    stray keyword or operator.
+    "
+    );
+
+    test_report!(
+        list_mixed_separators,
+        indoc!(
+            r"
+            [
+                1,
+                2
+                3,
+            ]
+            "
+        ),
+        @r"
+    ── MIXED LIST SEPARATORS in tmp/list_mixed_separators/Test.roc ─────────────────
+
+    I am partway through parsing a list, but I got stuck here:
+
+    4│      [
+    5│          1,
+    6│          2
+    7│          3,
+                ^
+
+    The elements of a list are separated either by commas, like [1, 2], or
+    by putting each one on its own line. This list does both, so try using
+    just one of those!
+    "
+    );
+
+    test_report!(
+        list_newline_separated_misaligned,
+        indoc!(
+            r"
+            nums = [
+                1
+              2
+                3
+            ]
+
+            nums
+            "
+        ),
+        @r"
+    ── MISALIGNED LIST ELEMENT in tmp/list_newline_separated_misaligned/Test.roc ───
+
+    I am partway through parsing a list, but I got stuck here:
+
+    4│      nums = [
+    5│          1
+    6│        2
+              ^
+
+    When the elements of a list are separated by putting each one on its
+    own line, they all need to start in the same column. Try lining this
+    element up with the first one!
+    "
+    );
+
+    test_report!(
+        number_double_dot,
+        indoc!(
+            r"
+            1.1.1
+            "
+        ),
+        @r"
+    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+
+    This float literal contains an invalid digit:
+
+    4│      1.1.1
+            ^^^^^
+
+    Floating point literals can only contain the digits 0-9, or use
+    scientific notation 10e4, or have a float suffix.
+
+    Tip: Learn more about number literals at TODO
+    "
+    );
+
+    test_report!(
+        leading_plus_sign,
+        indoc!(
+            r"
+            +5
+            "
+        ),
+        @r###"
+    ── LEADING PLUS SIGN in tmp/leading_plus_sign/Test.roc ─────────────────────────
+
+    I am partway through parsing an expression, but I got stuck here:
+
+    1│  app "test" provides [main] to "./platform"
+    2│
+    3│  main =
+    4│      +5
+            ^
+
+    Roc numbers can't start with a +, so try removing it. For example,
+    write 5 instead of +5.
+    "###
+    );
+
+    test_report!(
+        leading_star_operator,
+        indoc!(
+            r"
+            * 2
+            "
+        ),
+        @r###"
+    ── MISSING LEFT OPERAND in tmp/leading_star_operator/Test.roc ──────────────────
+
+    I am partway through parsing an expression, but I got stuck here:
+
+    1│  app "test" provides [main] to "./platform"
+    2│
+    3│  main =
+    4│      * 2
+            ^
+
+    This * operator needs a value on its left, like a * b.
+
+    Tip: To pass an operator to a function, wrap it in a lambda, like
+    \a, b -> a * b.
+    "###
+    );
+
+    test_report!(
+        pipeline_operator_not_indented,
+        indoc!(
+            r"
+            result =
+                list
+            |> f
+
+            result
+            "
+        ),
+        @r###"
+    ── UNINDENTED OPERATOR in tmp/pipeline_operator_not_indented/Test.roc ──────────
+
+    I am partway through parsing an expression, but I got stuck here:
 
-    4│      [1, 2,
-    5│
-    6│
-        ^
+    1│  app "test" provides [main] to "./platform"
+    2│
+    3│  main =
+    4│      result =
+    5│          list
+    6│      |> f
+            ^
 
-    I was expecting to see a closing square bracket before this, so try
-    adding a ] and see if that helps?
+    This operator starts a new line, but it is not indented enough to
+    continue the expression above it. Try indenting it further, like so:
 
-    Note: When I get stuck like this, it usually means that there is a
-    missing parenthesis or bracket somewhere earlier. It could also be a
-    stray keyword or operator.
-    "
+        result =
+            list
+            |> List.map f
+    "###
     );
 
     test_report!(
-        number_double_dot,
+        record_not_a_pattern,
         indoc!(
             r"
-            1.1.1
+            { x: f y } : T
+
+            1
             "
         ),
-        @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
-
-    This float literal contains an invalid digit:
+        @r###"
+    ── RECORD IS NOT A PATTERN in tmp/record_not_a_pattern/Test.roc ────────────────
 
-    4│      1.1.1
-            ^^^^^
+    I am partway through parsing a definition, but this record is not a
+    pattern:
 
-    Floating point literals can only contain the digits 0-9, or use
-    scientific notation 10e4, or have a float suffix.
+    1│  app "test" provides [main] to "./platform"
+    2│
+    3│  main =
+    4│      { x: f y } : T
+            ^^^^^^^^^^
 
-    Tip: Learn more about number literals at TODO
-    "
+    Only a record destructure like { x, y } can go before a :. To give a
+    record value a type, name it first, like point : { x : I64 }.
+    "###
     );
 
     test_report!(
@@ -5654,6 +6344,61 @@ mod test_reporting {
     "#
     );
 
+    test_report!(
+        unicode_surrogate,
+        r#""abc\u(D800)def""#,
+        @r#"
+    ── INVALID UNICODE in /code/proj/Main.roc ──────────────────────────────────────
+
+    This unicode code point is a surrogate:
+
+    4│      "abc\u(D800)def"
+                   ^^^^
+
+    Code points from \u(D800) to \u(DFFF) are reserved for UTF-16.
+
+    Learn more about working with unicode in roc at TODO
+    "#
+    );
+
+    #[test]
+    fn unicode_surrogate_runtime_error() {
+        // The string is replaced by a runtime error, which is reported like the problem itself
+        let src = r#""abc\u(D800)def""#;
+
+        let arena = Bump::new();
+        let (_type_problems, can_problems, home, interns) =
+            infer_expr_help(&arena, src).expect("parse error");
+
+        let region = match can_problems.as_slice() {
+            [Problem::UnicodeSurrogateCodePt(region)] => *region,
+            other => panic!("Expected a surrogate code point problem, but got {other:?}"),
+        };
+
+        let src_lines: Vec<&str> = src.split('\n').collect();
+        let lines = LineInfo::new(src);
+        let alloc = RocDocAllocator::new(&src_lines, home, &interns);
+
+        let problem = Problem::RuntimeError(RuntimeError::UnicodeSurrogateCodePt(region));
+        let filename = filename_from_string(r"/code/proj/Main.roc");
+
+        let mut buf = String::new();
+        can_problem(&alloc, &lines, filename, problem).render_ci(&mut buf, &alloc);
+
+        insta::assert_snapshot!(buf, @r#"
+        ── INVALID UNICODE in /code/proj/Main.roc ──────────────────────────────────────
+
+        This unicode code point is a surrogate:
+
+        1│  "abc\u(D800)def"
+                   ^^^^
+
+        Code points from \u(D800) to \u(DFFF) are reserved for UTF-16.
+
+        Learn more about working with unicode in roc at TODO
+        "#);
+    }
+
     test_report!(
         weird_escape,
         r#""abc\qdef""#,
@@ -5673,7 +6418,81 @@ mod test_reporting {
         - A tab: \t
         - An escaped quote: \"
         - An escaped backslash: \\
+        - A null character: \0
+        - An ASCII character: \x41
+        - A unicode code point: \u(00FF)
+    "#
+    );
+
+    test_report!(
+        weird_byte_escape,
+        r#""abc\xZdef""#,
+        @r#"
+    ── WEIRD BYTE ESCAPE in tmp/weird_byte_escape/Test.roc ─────────────────────────
+
+    I am partway through parsing a byte escape, but I got stuck here:
+
+    4│      "abc\xZdef"
+                ^^
+
+    I was expecting exactly two hexadecimal digits, like \x41 or \x0A.
+    "#
+    );
+
+    test_report!(
+        bad_escapes_in_one_string,
+        indoc!(
+            r#"
+            x = "a\qb\xZc"
+
+            x
+            "#
+        ),
+        @r###"
+    ── BAD ESCAPES in tmp/bad_escapes_in_one_string/Test.roc ───────────────────────
+
+    This string literal has 2 escape sequences that I could not parse.
+
+    I was partway through parsing a  string literal, but I got stuck here:
+
+    4│      x = "a\qb\xZc"
+                  ^^
+
+    This is not an escape sequence I recognize. After a backslash, I am
+    looking for one of these:
+
+        - A newline: \n
+        - A caret return: \r
+        - A tab: \t
+        - An escaped quote: \"
+        - An escaped backslash: \\
+        - A null character: \0
+        - An ASCII character: \x41
         - A unicode code point: \u(00FF)
+
+    I am partway through parsing a byte escape, but I got stuck here:
+
+    4│      x = "a\qb\xZc"
+                     ^^
+
+    I was expecting exactly two hexadecimal digits, like \x41 or \x0A.
+    "###
+    );
+
+    test_report!(
+        non_ascii_byte_escape,
+        r#""abc\xFFdef""#,
+        @r#"
+    ── NON-ASCII BYTE ESCAPE in tmp/non_ascii_byte_escape/Test.roc ─────────────────
+
+    This byte escape is not an ASCII character:
+
+    4│      "abc\xFFdef"
+                ^^^^
+
+    Strings are UTF-8, so a \x escape can only be used for ASCII
+    characters, from \x00 to \x7F. For other characters, use a unicode
+    code point instead, like \u(00FF).
     "#
     );
 
@@ -5767,6 +6586,34 @@ mod test_reporting {
     "#
     );
 
+    test_report!(
+        expect_condition_not_indented,
+        indoc!(
+            r"
+            expect
+            1 == 1
+
+            x
+            "
+        ),
+        @r###"
+    ── MISSING EXPRESSION in tmp/expect_condition_not_indented/Test.roc ────────────
+
+    I am partway through parsing `expect`, but I got stuck here:
+
+    1│  app "test" provides [main] to "./platform"
+    2│
+    3│  main =
+    4│      expect
+                  ^
+
+    I was expecting to see an expression after the `expect` keyword. If it
+    goes on the next line, it must be indented more than the keyword, like
+
+        expect 1 + 1 == 2
+    "###
+    );
+
     // https://github.com/roc-lang/roc/issues/1714
     test_report!(
     interpolate_concat_is_transparent_1714,
@@ -5960,6 +6807,52 @@ All branches in an `if` must have the same type!
     "
     );
 
+    test_report!(
+        closure_or_pattern,
+        indoc!(
+            r"
+            \Red | Green -> 1
+            "
+        ),
+        @r"
+    ── UNEXPECTED OR-PATTERN in tmp/closure_or_pattern/Test.roc ────────────────────
+
+    I am partway through parsing a function argument list, but I got stuck
+    here:
+
+    4│      \Red | Green -> 1
+                 ^
+
+    Alternative patterns like Red | Green can only be used in `when`
+    branches. Try matching on the argument with a `when` in the function
+    body instead.
+    "
+    );
+
+    test_report!(
+        closure_body_not_indented,
+        indoc!(
+            r"
+            foo =
+                Task.await task \result ->
+                x = result
+                x
+            foo
+            "
+        ),
+        @r"
+    ── UNFINISHED FUNCTION in tmp/closure_body_not_indented/Test.roc ───────────────
+
+    I was partway through parsing a  function, but I got stuck here:
+
+    5│          Task.await task \result ->
+                                          ^
+
+    I just saw a ->, so I was expecting to see the function body next,
+    indented more than the line the function starts on.
+    "
+    );
+
     test_report!(
         #[ignore]
         double_binop,
@@ -6058,6 +6951,30 @@ All branches in an `if` must have the same type!
     "#
     );
 
+    test_report!(
+        single_pipe_operator,
+        indoc!(
+            r"
+            x = Bool.true | Bool.false
+
+            x
+            "
+        ),
+        @r###"
+    ── UNKNOWN OPERATOR in tmp/single_pipe_operator/Test.roc ───────────────────────
+
+    This looks like an operator, but it's not one I recognize!
+
+    1│  app "test" provides [main] to "./platform"
+    2│
+    3│  main =
+    4│      x = Bool.true | Bool.false
+                          ^
+
+    Maybe you want || or |> instead?
+    "###
+    );
+
     test_report!(
         double_plus,
         indoc!(
@@ -10711,6 +11628,112 @@ All branches in an `if` must have the same type!
     "#
     );
 
+    test_report!(
+        spread_in_list,
+        indoc!(
+            r"
+            [1, ..[2, 3]]
+            "
+        ),
+        @r"
+    ── SPREAD NOT SUPPORTED in /code/proj/Main.roc ─────────────────────────────────
+
+    This spread is not supported yet:
+
+    4│      [1, ..[2, 3]]
+                ^^^^^^^^
+
+    Lists and tuples can't splat other collections into them. Try
+    List.concat to combine lists instead.
+    "
+    );
+
+    test_report!(
+        spread_in_parens,
+        indoc!(
+            r"
+            xs = (..x)
+
+            xs
+            "
+        ),
+        @r"
+    ── BAD SPREAD in tmp/spread_in_parens/Test.roc ─────────────────────────────────
+
+    I am partway through parsing a spread, but I got stuck here:
+
+    4│      xs = (..x)
+                  ^^^
+
+    A spread can only be an element of a list, like [a, ..rest], or of a
+    tuple with other elements, like (a, ..rest).
+    "
+    );
+
+    test_report!(
+        spread_with_three_dots,
+        indoc!(
+            r"
+            xs = [...x]
+
+            xs
+            "
+        ),
+        @r"
+    ── BAD SPREAD in tmp/spread_with_three_dots/Test.roc ───────────────────────────
+
+    I am partway through parsing a spread, but I got stuck here:
+
+    4│      xs = [...x]
+                  ^^^
+
+    A spread is written with two dots, like ..rest.
+    "
+    );
+
+    test_report!(
+        spread_of_application,
+        indoc!(
+            r"
+            xs = [..f x]
+
+            xs
+            "
+        ),
+        @r"
+    ── BAD SPREAD in tmp/spread_of_application/Test.roc ────────────────────────────
+
+    I am partway through parsing a spread, but I got stuck here:
+
+    4│      xs = [..f x]
+                  ^^^
+
+    A spread only takes the single value right after it. To spread a
+    bigger expression, wrap it in parentheses, like ..(List.reverse xs).
+    "
+    );
+
+    test_report!(
+        spread_missing_value,
+        indoc!(
+            r"
+            xs = [1, ..]
+
+            xs
+            "
+        ),
+        @r"
+    ── BAD SPREAD in tmp/spread_missing_value/Test.roc ─────────────────────────────
+
+    I am partway through parsing a spread, but I got stuck here:
+
+    4│      xs = [1, ..]
+                     ^^
+
+    I was expecting a value right after the .., like ..rest.
+    "
+    );
+
     // CalledVia::RecordBuilder => {
     //     alloc.concat([
     //         alloc.note(""),
@@ -443,13 +443,18 @@ fn contains_unexposed_type(
 
         Malformed(_) | Inferred | Wildcard | BoundVariable(_) => false,
 
-        Function(loc_args, loc_ret) => {
+        Function(loc_args, loc_ret, loc_effect) => {
             let loc_args_contains_unexposed_type = loc_args.iter().any(|loc_arg| {
                 contains_unexposed_type(&loc_arg.value, exposed_module_ids, module_ids)
             });
 
+            let loc_effect_contains_unexposed_type = loc_effect.iter().any(|loc_effect| {
+                contains_unexposed_type(&loc_effect.value, exposed_module_ids, module_ids)
+            });
+
             contains_unexposed_type(&loc_ret.value, exposed_module_ids, module_ids)
                 || loc_args_contains_unexposed_type
+                || loc_effect_contains_unexposed_type
         }
 
         Record { fields, ext } => {
@@ -611,7 +616,7 @@ fn type_to_docs(in_func_type_ann: bool, type_annotation: ast::TypeAnnotation) ->
         ast::TypeAnnotation::SpaceAfter(&sub_type_ann, _) => {
             type_to_docs(in_func_type_ann, sub_type_ann)
         }
-        ast::TypeAnnotation::Function(ast_arg_anns, output_ann) => {
+        ast::TypeAnnotation::Function(ast_arg_anns, output_ann, _effect_ann) => {
             let mut doc_arg_anns = Vec::new();
 
             for ast_arg_ann in ast_arg_anns {
@@ -1612,6 +1612,70 @@ fn cannot_use_original_name_if_imported_with_alias() {
     multiple_modules("cannot_use_original_name_if_imported_with_alias", modules).unwrap_err();
 }
 
+#[test]
+fn import_exposed_with_alias() {
+    let modules = vec![
+        (
+            "Dep.roc",
+            indoc!(
+                r#"
+                interface Dep exposes [hello, Greeting] imports []
+
+                Greeting : Str
+
+                hello : Greeting
+                hello = "Hello, World!\n"
+                "#
+            ),
+        ),
+        (
+            "Main.roc",
+            indoc!(
+                r#"
+                interface Main exposes [main] imports []
+
+                import Dep exposing [hello as greeting, Greeting as Message]
+
+                main : Message
+                main = greeting
+                "#
+            ),
+        ),
+    ];
+    let loaded_module = multiple_modules("import_exposed_with_alias", modules);
+    assert!(loaded_module.is_ok(), "should check");
+}
+
+#[test]
+fn cannot_use_original_name_if_exposed_with_alias() {
+    let modules = vec![
+        (
+            "Dep.roc",
+            indoc!(
+                r#"
+                interface Dep exposes [hello] imports []
+
+                hello = "Hello, World!\n"
+                "#
+            ),
+        ),
+        (
+            "Main.roc",
+            indoc!(
+                r#"
+                interface Main exposes [main] imports []
+
+                import Dep exposing [hello as greeting]
+
+                main = hello
+                "#
+            ),
+        ),
+    ];
+
+    multiple_modules("cannot_use_original_name_if_exposed_with_alias", modules).unwrap_err();
+}
+
 #[test]
 fn module_params_checks() {
     let modules = vec![
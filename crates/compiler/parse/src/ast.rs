@@ -21,6 +21,13 @@ pub struct FullAst<'a> {
     pub defs: Defs<'a>,
 }
 
+/// A parsed module whose header is optional, e.g. a file that is only defs.
+#[derive(Debug, Clone)]
+pub struct Module<'a> {
+    pub header: Option<SpacesBefore<'a, Header<'a>>>,
+    pub defs: Defs<'a>,
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Spaces<'a, T> {
     pub before: &'a [CommentOrNewline<'a>],
@@ -224,7 +231,7 @@ impl<'a> Header<'a> {
     fn header_import_to_value_def(
         pkg_name: Option<&'a str>,
         name: header::ModuleName<'a>,
-        exposed: Collection<'a, Loc<Spaced<'a, header::ExposedName<'a>>>>,
+        exposed: Collection<'a, Loc<Spaced<'a, ImportExposedName<'a>>>>,
         region: Region,
     ) -> ValueDef<'a> {
         use crate::header::KeywordItem;
@@ -274,6 +281,14 @@ pub struct WhenBranch<'a> {
     pub guard: Option<Loc<Expr<'a>>>,
 }
 
+/// The left-hand side of a backpassing arrow, e.g. `a, b` in `a, b <- f`.
+/// A single pattern may have a type annotation, as in `x : I64 <- f`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BackpassingPatterns<'a> {
+    pub patterns: &'a [Loc<Pattern<'a>>],
+    pub ann: Option<Loc<TypeAnnotation<'a>>>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct WhenPattern<'a> {
     pub pattern: Loc<Pattern<'a>>,
@@ -305,6 +320,8 @@ pub enum EscapedChar {
     Backslash,      // \\
     CarriageReturn, // \r
     Dollar,         // \$
+    Null,           // \0
+    Byte(u8),       // \x41, always an ASCII byte
 }
 
 impl EscapedChar {
@@ -320,6 +337,8 @@ impl EscapedChar {
             Tab => 't',
             Newline => 'n',
             Dollar => '$',
+            Null => '0',
+            Byte(_) => 'x',
         }
     }
 
@@ -334,6 +353,8 @@ impl EscapedChar {
             Tab => '\t',
             Newline => '\n',
             Dollar => '$',
+            Null => '\0',
+            Byte(byte) => byte as char,
         }
     }
 }
@@ -455,6 +476,10 @@ pub enum Expr<'a> {
 
     Tuple(Collection<'a, &'a Loc<Expr<'a>>>),
 
+    /// A spread element in a list or tuple, e.g. the `..rest` in `[a, ..rest]`.
+    /// This is distinct from the `..` list-rest pattern; spreads are only parsed so far.
+    Spread(&'a Loc<Expr<'a>>),
+
     /// Mapper-based record builders, e.g.
     /// { Task.parallel <-
     ///     foo: Task.getData Foo,
@@ -487,7 +512,11 @@ pub enum Expr<'a> {
     /// Multiple defs in a row
     Defs(&'a Defs<'a>, &'a Loc<Expr<'a>>),
 
-    Backpassing(&'a [Loc<Pattern<'a>>], &'a Loc<Expr<'a>>, &'a Loc<Expr<'a>>),
+    Backpassing(
+        &'a BackpassingPatterns<'a>,
+        &'a Loc<Expr<'a>>,
+        &'a Loc<Expr<'a>>,
+    ),
     Expect(&'a Loc<Expr<'a>>, &'a Loc<Expr<'a>>),
 
     Dbg,
@@ -653,6 +682,7 @@ pub fn is_expr_suffixed(expr: &Expr) -> bool {
             .iter()
             .any(|field| is_assigned_value_suffixed(&field.value)),
         Expr::Tuple(items) => items.iter().any(|x| is_expr_suffixed(&x.value)),
+        Expr::Spread(a) => is_expr_suffixed(&a.value),
         Expr::RecordBuilder { mapper: _, fields } => fields
             .iter()
             .any(|field| is_assigned_value_suffixed(&field.value)),
@@ -954,7 +984,7 @@ impl<'a, 'b> RecursiveValueDefIter<'a, 'b> {
                     }
                     expr_stack.push(&expr.value);
                 }
-                UnaryOp(expr, _) => expr_stack.push(&expr.value),
+                UnaryOp(expr, _) | Spread(expr) => expr_stack.push(&expr.value),
                 If {
                     if_thens,
                     final_else,
@@ -1101,7 +1131,7 @@ pub struct ModuleImport<'a> {
         header::KeywordItem<
             'a,
             ImportExposingKeyword,
-            Collection<'a, Loc<Spaced<'a, header::ExposedName<'a>>>>,
+            Collection<'a, Loc<Spaced<'a, ImportExposedName<'a>>>>,
         >,
     >,
 }
@@ -1174,6 +1204,29 @@ impl<'a> ImportAlias<'a> {
     }
 }
 
+/// An entry in an import's exposing list, e.g. `map` or `map as listMap`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImportExposedName<'a> {
+    pub name: header::ExposedName<'a>,
+    pub alias: Option<header::KeywordItem<'a, ImportAsKeyword, Loc<header::ExposedName<'a>>>>,
+}
+
+impl<'a> ImportExposedName<'a> {
+    pub const fn new(name: header::ExposedName<'a>) -> Self {
+        ImportExposedName { name, alias: None }
+    }
+
+    /// The name this entry is known by in the importing module
+    pub fn local_name(&self) -> header::ExposedName<'a> {
+        match self.alias {
+            Some(alias) => alias.item.value,
+            None => self.name,
+        }
+    }
+}
+
+/// When there are no defs at all, `spaces` holds the comments of what would
+/// otherwise be an empty module, so that formatting it keeps them.
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct Defs<'a> {
     pub tags: std::vec::Vec<EitherIndex<TypeDef<'a>, ValueDef<'a>>>,
@@ -1508,8 +1561,13 @@ impl ImplementsAbilities<'_> {
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum TypeAnnotation<'a> {
-    /// A function. The types of its arguments, then the type of its return value.
-    Function(&'a [Loc<TypeAnnotation<'a>>], &'a Loc<TypeAnnotation<'a>>),
+    /// A function. The types of its arguments, then the type of its return value,
+    /// then the effect it performs, if any, e.g. the `Effect` in `Str -> {} | Effect`.
+    Function(
+        &'a [Loc<TypeAnnotation<'a>>],
+        &'a Loc<TypeAnnotation<'a>>,
+        Option<&'a Loc<TypeAnnotation<'a>>>,
+    ),
 
     /// Applying a type to some arguments (e.g. Map.Map String Int)
     Apply(&'a str, &'a str, &'a [Loc<TypeAnnotation<'a>>]),
@@ -2454,12 +2512,13 @@ impl<'a> Malformed for Expr<'a> {
             RecordUpdate { update, fields } => update.is_malformed() || fields.is_malformed(),
             Record(items) => items.is_malformed(),
             Tuple(items) => items.is_malformed(),
+            Spread(inner) => inner.is_malformed(),
 
             RecordBuilder { mapper: map2, fields } => map2.is_malformed() || fields.is_malformed(),
 
             Closure(args, body) => args.iter().any(|arg| arg.is_malformed()) || body.is_malformed(),
             Defs(defs, body) => defs.is_malformed() || body.is_malformed(),
-            Backpassing(args, call, body) => args.iter().any(|arg| arg.is_malformed()) || call.is_malformed() || body.is_malformed(),
+            Backpassing(lhs, call, body) => lhs.is_malformed() || call.is_malformed() || body.is_malformed(),
             Expect(condition, continuation) => condition.is_malformed() || continuation.is_malformed(),
             Dbg => false,
             DbgStmt(condition, continuation) => condition.is_malformed() || continuation.is_malformed(),
@@ -2493,6 +2552,12 @@ impl<'a> Malformed for WhenBranch<'a> {
     }
 }
 
+impl<'a> Malformed for BackpassingPatterns<'a> {
+    fn is_malformed(&self) -> bool {
+        self.patterns.iter().any(|pat| pat.is_malformed()) || self.ann.is_malformed()
+    }
+}
+
 impl<'a, T: Malformed> Malformed for Collection<'a, T> {
     fn is_malformed(&self) -> bool {
         self.iter().any(|item| item.is_malformed())
@@ -2729,8 +2794,10 @@ impl<'a> Malformed for ModuleImportParams<'a> {
 impl<'a> Malformed for TypeAnnotation<'a> {
     fn is_malformed(&self) -> bool {
         match self {
-            TypeAnnotation::Function(args, ret) => {
-                args.iter().any(|arg| arg.is_malformed()) || ret.is_malformed()
+            TypeAnnotation::Function(args, ret, effect) => {
+                args.iter().any(|arg| arg.is_malformed())
+                    || ret.is_malformed()
+                    || effect.iter().any(|effect| effect.is_malformed())
             }
             TypeAnnotation::Apply(_, _, args) => args.iter().any(|arg| arg.is_malformed()),
             TypeAnnotation::BoundVariable(_)
@@ -0,0 +1,317 @@
+//! Collects the `##` doc comments of a module, without walking its AST.
+use roc_region::all::{Loc, Position, Region};
+
+use crate::ast::{TypeDef, ValueDef};
+use crate::blankspace::fast_eat_until_control_character;
+use crate::header::parse_module;
+
+/// A top-level def, as found by [`doc_comments`].
+pub type DocumentedDef<'a> = Result<Loc<TypeDef<'a>>, Loc<ValueDef<'a>>>;
+
+/// Returns every doc comment in `src`, in source order, along with the
+/// top-level def it documents.
+///
+/// A doc comment documents the def that immediately follows it, with only
+/// other doc comments in between. A blank line or a regular comment detaches
+/// it, as does any code (e.g. for doc comments inside a def's body).
+///
+/// The text of each doc comment excludes its leading `##` and one space.
+/// If `src` doesn't parse, no doc comment is associated with a def.
+pub fn doc_comments<'a>(
+    arena: &'a bumpalo::Bump,
+    src: &'a str,
+) -> Vec<(Region, &'a str, Option<DocumentedDef<'a>>)> {
+    let regions = doc_comment_regions(src);
+
+    if regions.is_empty() {
+        return Vec::new();
+    }
+
+    // Only the parser knows where each def starts (a def can span many lines,
+    // and be preceded by an annotation), so we still parse the module here.
+    // The module's top-level defs are all we look at, though.
+    let defs: Vec<DocumentedDef<'a>> = match parse_module(arena, src) {
+        Ok(module) => module.defs.loc_defs().collect(),
+        Err(_) => Vec::new(),
+    };
+
+    regions
+        .into_iter()
+        .map(|region| {
+            let def = documented_def(src, region, &defs);
+
+            (region, doc_comment_text(src, region), def)
+        })
+        .collect()
+}
+
+/// Finds the doc comments in `src` with a single pass over its bytes, skipping
+/// over string and character literals so a `##` inside one isn't mistaken for
+/// a doc comment.
+fn doc_comment_regions(src: &str) -> Vec<Region> {
+    let bytes = src.as_bytes();
+    let mut regions = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let rest = &bytes[index..];
+
+        if rest.starts_with(b"\"\"\"") {
+            index += 3 + literal_len(&rest[3..], b"\"\"\"", false);
+        } else if rest[0] == b'"' || rest[0] == b'\'' {
+            index += 1 + literal_len(&rest[1..], &rest[..1], true);
+        } else if rest[0] == b'#' {
+            let len = fast_eat_until_control_character(rest);
+
+            if is_doc_comment(rest) {
+                regions.push(Region::new(
+                    Position::new(index as u32),
+                    Position::new((index + len) as u32),
+                ));
+            }
+
+            index += len;
+        } else {
+            index += 1;
+        }
+    }
+
+    regions
+}
+
+/// The length of a string or character literal's contents, including the
+/// `closing` delimiter. An unclosed single-line literal stops at the newline.
+fn literal_len(bytes: &[u8], closing: &[u8], single_line: bool) -> usize {
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let rest = &bytes[index..];
+
+        if rest.starts_with(closing) {
+            return index + closing.len();
+        } else if rest[0] == b'\\' {
+            index += 2;
+        } else if single_line && rest[0] == b'\n' {
+            return index;
+        } else {
+            index += 1;
+        }
+    }
+
+    bytes.len()
+}
+
+/// Whether a comment is a doc comment: `##`, but not `###`
+fn is_doc_comment(comment: &[u8]) -> bool {
+    comment.starts_with(b"##") && comment.get(2) != Some(&b'#')
+}
+
+fn doc_comment_text(src: &str, region: Region) -> &str {
+    let comment = &src[region.start().offset as usize..region.end().offset as usize];
+    let text = comment.strip_prefix("##").unwrap_or(comment);
+
+    text.strip_prefix(' ').unwrap_or(text)
+}
+
+fn documented_def<'a>(
+    src: &str,
+    region: Region,
+    defs: &[DocumentedDef<'a>],
+) -> Option<DocumentedDef<'a>> {
+    let def_region = |def: &DocumentedDef| match def {
+        Ok(loc_def) => loc_def.region,
+        Err(loc_def) => loc_def.region,
+    };
+
+    // The defs are in source order, so the first one after the comment is the
+    // only one it could be documenting.
+    let index = defs.partition_point(|def| def_region(def).start() < region.end());
+    let def = defs.get(index)?;
+
+    let gap = &src[region.end().offset as usize..def_region(def).start().offset as usize];
+
+    if only_doc_comments_between(gap) {
+        Some(*def)
+    } else {
+        None
+    }
+}
+
+/// Whether `gap`, which runs from the end of a doc comment to the start of a
+/// def, contains nothing but line breaks into further doc comments.
+fn only_doc_comments_between(gap: &str) -> bool {
+    let mut lines = gap.split('\n');
+
+    // The rest of the doc comment's own line
+    if !lines.next().is_some_and(|rest| rest.trim().is_empty()) {
+        return false;
+    }
+
+    // The indentation before the def
+    if !lines
+        .next_back()
+        .is_some_and(|indent| indent.trim().is_empty())
+    {
+        return false;
+    }
+
+    lines.all(|line| is_doc_comment(line.trim_start().as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use bumpalo::Bump;
+    use roc_region::all::Position;
+
+    use super::*;
+    use crate::ast::Pattern;
+
+    fn documented_name<'a>(def: &Option<DocumentedDef<'a>>) -> Option<&'a str> {
+        match def {
+            Some(Err(loc_def)) => match loc_def.value {
+                ValueDef::Annotation(Loc { value, .. }, _)
+                | ValueDef::AnnotatedBody {
+                    ann_pattern: &Loc { value, .. },
+                    ..
+                }
+                | ValueDef::Body(&Loc { value, .. }, _) => match value {
+                    Pattern::Identifier { ident } => Some(ident),
+                    _ => None,
+                },
+                _ => None,
+            },
+            Some(Ok(loc_def)) => match loc_def.value {
+                TypeDef::Alias { header, .. } => Some(header.name.value),
+                _ => None,
+            },
+            None => None,
+        }
+    }
+
+    #[test]
+    fn test_doc_comments_are_associated_with_following_def() {
+        let arena = Bump::new();
+        let src = indoc::indoc!(
+            r#"
+            module [one, Two, three]
+
+            ## The number one.
+            ## Not to be confused with two.
+            one : U8
+            one = 1
+
+            ## A pair.
+            Two : (U8, U8)
+
+            three = 3
+            "#
+        );
+
+        let comments = doc_comments(&arena, src);
+
+        assert_eq!(
+            comments
+                .iter()
+                .map(|(_, text, def)| (*text, documented_name(def)))
+                .collect::<Vec<_>>(),
+            vec![
+                ("The number one.", Some("one")),
+                ("Not to be confused with two.", Some("one")),
+                ("A pair.", Some("Two")),
+            ]
+        );
+
+        assert_eq!(
+            comments[0].0,
+            Region::new(Position::new(26), Position::new(44))
+        );
+        assert_eq!(
+            comments[0].2.map(|def| def.unwrap_err().region.start()),
+            Some(Position::new(77))
+        );
+    }
+
+    #[test]
+    fn test_doc_comments_detached_by_blank_line_or_comment() {
+        let arena = Bump::new();
+        let src = indoc::indoc!(
+            r#"
+            ## Detached by a blank line
+
+            x = 1
+
+            ## Detached by a comment
+            # (a regular one)
+            y = 2
+            "#
+        );
+
+        let comments = doc_comments(&arena, src);
+
+        assert_eq!(
+            comments
+                .iter()
+                .map(|(_, text, def)| (*text, documented_name(def)))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Detached by a blank line", None),
+                ("Detached by a comment", None)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_doc_comments_skip_strings_and_triple_hashes() {
+        let arena = Bump::new();
+        let src = indoc::indoc!(
+            r###"
+            ### Not a doc comment
+            x = "## not one either"
+
+            y = """
+                ## nor this
+                """
+
+            ## Documented
+            z = '#'
+            "###
+        );
+
+        let comments = doc_comments(&arena, src);
+
+        assert_eq!(
+            comments
+                .iter()
+                .map(|(_, text, def)| (*text, documented_name(def)))
+                .collect::<Vec<_>>(),
+            vec![("Documented", Some("z"))]
+        );
+    }
+
+    #[test]
+    fn test_doc_comments_inside_def_body_are_detached() {
+        let arena = Bump::new();
+        let src = indoc::indoc!(
+            r#"
+            ## Documented
+            x =
+                ## Not documenting anything
+                y = 1
+                y
+            "#
+        );
+
+        let comments = doc_comments(&arena, src);
+
+        assert_eq!(
+            comments
+                .iter()
+                .map(|(_, text, def)| (*text, documented_name(def)))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Documented", Some("x")),
+                ("Not documenting anything", None)
+            ]
+        );
+    }
+}
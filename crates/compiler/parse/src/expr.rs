@@ -1,27 +1,28 @@
 use crate::ast::{
-    is_expr_suffixed, AssignedField, Collection, CommentOrNewline, Defs, Expr, ExtractSpaces,
-    Implements, ImplementsAbilities, ImportAlias, ImportAsKeyword, ImportExposingKeyword,
-    ImportedModuleName, IngestedFileAnnotation, IngestedFileImport, ModuleImport,
-    ModuleImportParams, Pattern, Spaceable, Spaced, Spaces, SpacesBefore, TryTarget,
-    TypeAnnotation, TypeDef, TypeHeader, ValueDef,
+    is_expr_suffixed, AssignedField, BackpassingPatterns, Collection, CommentOrNewline, Defs, Expr,
+    ExtractSpaces, Implements, ImplementsAbilities, ImportAlias, ImportAsKeyword,
+    ImportExposedName, ImportExposingKeyword, ImportedModuleName, IngestedFileAnnotation,
+    IngestedFileImport, ModuleImport, ModuleImportParams, Pattern, PatternAs, Spaceable, Spaced,
+    Spaces, SpacesBefore, StrLiteral, StrSegment, TryTarget, TypeAnnotation, TypeDef, TypeHeader,
+    ValueDef,
 };
 use crate::blankspace::{
-    loc_space0_e, require_newline_or_eof, space0_after_e, space0_around_ee, space0_before_e,
-    space0_before_optional_after, space0_e, spaces, spaces_around, spaces_before,
+    check_indent, loc_space0_e, require_newline_or_eof, space0_after_e, space0_around_ee,
+    space0_before_e, space0_before_optional_after, space0_e, spaces, spaces_around, spaces_before,
 };
 use crate::header::module_name_help;
 use crate::ident::{
     integer_ident, lowercase_ident, parse_ident, unqualified_ident, Accessor, Ident, Suffix,
 };
 use crate::parser::{
-    self, and, backtrackable, between, byte, byte_indent, collection_inner,
-    collection_trailing_sep_e, either, increment_min_indent, indented_seq_skip_first, loc, map,
-    map_with_arena, optional, reset_min_indent, sep_by1, sep_by1_e, set_min_indent, skip_first,
-    skip_second, specialize_err, specialize_err_ref, then, two_bytes, zero_or_more, EClosure,
-    EExpect, EExpr, EIf, EImport, EImportParams, EInParens, EList, ENumber, EPattern, ERecord,
-    EString, EType, EWhen, Either, ParseResult, Parser, SpaceProblem,
+    self, and, backtrackable, byte, byte_indent, collection_inner, collection_trailing_sep_e,
+    either, increment_min_indent, indented_seq_skip_first, loc, map, map_with_arena, optional,
+    peek, reset_min_indent, sep_by1, sep_by1_e, set_min_indent, skip_first, skip_second,
+    specialize_err, specialize_err_ref, then, two_bytes, zero_or_more, EClosure, EExpect, EExpr,
+    EIf, EImport, EImportParams, EInParens, EList, ENumber, EPattern, ERecord, ESpread, EString,
+    EType, EWhen, Either, ParseResult, Parser, SpaceProblem,
 };
-use crate::pattern::closure_param;
+use crate::pattern::{closure_param, pattern_as, with_pattern_as};
 use crate::state::State;
 use crate::string_literal::{self, StrLikeLiteral};
 use crate::type_annotation;
@@ -33,6 +34,8 @@ use roc_error_macros::internal_error;
 use roc_module::called_via::{BinOp, CalledVia, UnaryOp};
 use roc_region::all::{Loc, Position, Region};
 
+pub use roc_module::called_via::Associativity;
+
 use crate::parser::Progress::{self, *};
 
 fn expr_end<'a>() -> impl Parser<'a, (), EExpr<'a>> {
@@ -61,6 +64,58 @@ pub fn test_parse_expr<'a>(
     }
 }
 
+/// Parse a single expression at the start of `state` without requiring it to reach the end of
+/// the input, and return where parsing stopped along with the result, so callers can see how far
+/// it got. On success that's just past the expression; on failure it's where the error happened
+/// (see [EExpr::pos]).
+///
+/// Unlike [test_parse_expr], this doesn't parse a block of statements, so whatever follows the
+/// expression on the same line is left for the caller. A number can't be called like a
+/// function, so the expression also ends after a number that's followed by arguments, like the
+/// `2` in `1 + 2 extra`.
+pub fn parse_expr_partial<'a>(
+    arena: &'a Bump,
+    state: State<'a>,
+) -> (Result<Loc<Expr<'a>>, EExpr<'a>>, Position) {
+    let min_indent = 0;
+
+    match loc_expr(true).parse(arena, state.clone(), min_indent) {
+        Ok((_, loc_expr, new_state)) => match applied_number_end(&loc_expr.value) {
+            // parse again, but only up to the end of the number
+            Some(end) => parse_expr_partial(arena, state.truncate(end)),
+            None => (Ok(loc_expr), new_state.pos()),
+        },
+        Err((_, fail)) => {
+            let pos = fail.pos();
+
+            (Err(fail), pos)
+        }
+    }
+}
+
+/// The end of the first number literal in an operator chain that's applied to arguments,
+/// like the `2` in `1 + 2 extra`
+fn applied_number_end(expr: &Expr<'_>) -> Option<Position> {
+    let is_applied_number = |operand: &Expr<'_>| match operand.extract_spaces().item {
+        Expr::Apply(function, _, _) => match function.value.extract_spaces().item {
+            Expr::Num(_) | Expr::Float(_) | Expr::NonBase10Int { .. } => {
+                Some(function.region.end())
+            }
+            _ => None,
+        },
+        _ => None,
+    };
+
+    match expr.extract_spaces().item {
+        Expr::BinOps(lefts, last) => lefts
+            .iter()
+            .map(|(operand, _)| &operand.value)
+            .chain(std::iter::once(&last.value))
+            .find_map(is_applied_number),
+        _ => is_applied_number(expr),
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ExprParseOptions {
     /// Check for and accept multi-backpassing syntax
@@ -74,6 +129,14 @@ pub struct ExprParseOptions {
     ///
     /// > Just foo if foo == 2 -> ...
     pub check_for_arrow: bool,
+
+    /// Concatenate a string with adjacent strings on indented continuation lines
+    /// This is usually true, but false for list elements, because a string on
+    /// the next line starts the next element of a newline-separated list
+    ///
+    /// > ["a"
+    /// >  "b"]
+    pub concat_strs_across_lines: bool,
 }
 
 pub fn expr_help<'a>() -> impl Parser<'a, Expr<'a>, EExpr<'a>> {
@@ -91,10 +154,13 @@ fn loc_expr_in_parens_help<'a>() -> impl Parser<'a, Loc<Expr<'a>>, EInParens<'a>
             specialize_err_ref(
                 EInParens::Expr,
                 // space0_before_e(
-                loc_expr_block(false),
+                loc_collection_element(loc_expr_block(false)),
             ),
             byte(b',', EInParens::End),
-            byte(b')', EInParens::End),
+            one_of![
+                malformed_spread(EInParens::Expr),
+                byte(b')', EInParens::End)
+            ],
             Expr::SpaceBefore,
         )),
         move |arena, state, _, loc_elements| {
@@ -109,6 +175,15 @@ fn loc_expr_in_parens_help<'a>() -> impl Parser<'a, Loc<Expr<'a>>, EInParens<'a>
                 ))
             } else if elements.is_empty() {
                 Err((NoProgress, EInParens::Empty(state.pos())))
+            } else if let Expr::Spread(_) = elements.items[0].value.extract_spaces().item {
+                // a spread is only allowed among the elements of a tuple or a list
+                let region = elements.items[0].region;
+                let fail = EExpr::BadSpread(ESpread::InParens, region);
+
+                Err((
+                    MadeProgress,
+                    EInParens::Expr(arena.alloc(fail), region.start()),
+                ))
             } else {
                 // TODO: don't discard comments before/after
                 // (stored in the Collection)
@@ -187,7 +262,10 @@ fn loc_term_or_underscore_or_conditional<'a>(
         loc_expr_in_parens_etc_help(),
         loc(specialize_err(EExpr::If, if_expr_help(options))),
         loc(specialize_err(EExpr::When, when::when_expr_help(options))),
-        loc(specialize_err(EExpr::Str, string_like_literal_help())),
+        loc(specialize_err(
+            EExpr::Str,
+            concatenated_str_literal_help(options)
+        )),
         loc(specialize_err(
             EExpr::Number,
             positive_number_literal_help()
@@ -305,6 +383,7 @@ fn loc_possibly_negative_or_negated_term<'a>(
 
     one_of![
         parse_unary_negate,
+        unary_plus(),
         // this will parse negative numbers, which the unary negate thing up top doesn't (for now)
         loc(specialize_err(EExpr::Number, number_literal_help())),
         loc(map_with_arena(
@@ -320,10 +399,48 @@ fn loc_possibly_negative_or_negated_term<'a>(
     ]
 }
 
+/// The first term of an operator chain. An expression can't start with a binary operator,
+/// so if that's what we find, report the missing left operand instead of failing to start.
+fn loc_first_term<'a>(options: ExprParseOptions) -> impl Parser<'a, Loc<Expr<'a>>, EExpr<'a>> {
+    move |arena, state: State<'a>, min_indent: u32| {
+        match loc_possibly_negative_or_negated_term(options).parse(arena, state.clone(), min_indent)
+        {
+            Err((NoProgress, fail)) => {
+                match operator_help(EExpr::Start, EExpr::BadOperator, state.clone()) {
+                    // a `-` followed by whitespace is not a valid prefix, but is too easily
+                    // confused with negation to report as a missing operand
+                    Ok((_, OperatorOrDef::BinOp(op), after)) if op != BinOp::Minus => {
+                        let region = Region::new(state.pos(), after.pos());
+
+                        Err((MadeProgress, EExpr::MissingLeftOperand(op, region)))
+                    }
+                    _ => Err((NoProgress, fail)),
+                }
+            }
+            other => other,
+        }
+    }
+}
+
 fn fail_expr_start_e<'a, T: 'a>() -> impl Parser<'a, T, EExpr<'a>> {
     |_arena, state: State<'a>, _min_indent: u32| Err((NoProgress, EExpr::Start(state.pos())))
 }
 
+/// Roc has no unary plus, but numbers with a leading `+` are easy to paste in from elsewhere,
+/// so report those instead of failing to start an expression.
+fn unary_plus<'a, T: 'a>() -> impl Parser<'a, T, EExpr<'a>> {
+    |_arena: &'a Bump, state: State<'a>, _min_indent: u32| {
+        let region = Region::new(state.pos(), state.pos().bump_column(1));
+
+        match state.bytes() {
+            [b'+', digit, ..] if digit.is_ascii_digit() => {
+                Err((MadeProgress, EExpr::UnaryPlus(region)))
+            }
+            _ => Err((NoProgress, EExpr::Start(state.pos()))),
+        }
+    }
+}
+
 fn unary_negate<'a>() -> impl Parser<'a, (), EExpr<'a>> {
     move |_arena: &'a Bump, state: State<'a>, _min_indent: u32| {
         // a minus is unary iff
@@ -375,8 +492,7 @@ fn parse_expr_operator_chain<'a>(
 ) -> Result<(Progress, Expr<'a>, State<'a>), (Progress, EExpr<'a>)> {
     let line_indent = state.line_indent();
 
-    let (_, expr, state) =
-        loc_possibly_negative_or_negated_term(options).parse(arena, state, min_indent)?;
+    let (_, expr, state) = loc_first_term(options).parse(arena, state, min_indent)?;
 
     let mut initial_state = state.clone();
 
@@ -503,6 +619,7 @@ pub fn parse_repl_defs_and_optional_expr<'a>(
         ExprParseOptions {
             accept_multi_backpassing: true,
             check_for_arrow: true,
+            concat_strs_across_lines: true,
         },
         0,
         spaces_before,
@@ -572,8 +689,7 @@ fn parse_stmt_operator_chain<'a>(
 ) -> Result<(Progress, Stmt<'a>, State<'a>), (Progress, EExpr<'a>)> {
     let line_indent = state.line_indent();
 
-    let (_, expr, state) =
-        loc_possibly_negative_or_negated_term(options).parse(arena, state, min_indent)?;
+    let (_, expr, state) = loc_first_term(options).parse(arena, state, min_indent)?;
 
     let mut initial_state = state.clone();
     let end = state.pos();
@@ -832,6 +948,17 @@ fn to_call<'a>(
     }
 }
 
+/// The source of a number literal together with the `-` right in front of it, e.g. `-0.0`.
+fn negated_literal<'a>(state: &State<'a>, literal: &'a str) -> &'a str {
+    let bytes = &state.bytes()[..literal.len() + 1];
+
+    // the literal directly follows the `-`, so these bytes are the `-` and then the literal
+    debug_assert_eq!(bytes[0], b'-');
+    debug_assert_eq!(&bytes[1..], literal.as_bytes());
+
+    unsafe { std::str::from_utf8_unchecked(bytes) }
+}
+
 fn numeric_negate_expression<'a, T>(
     arena: &'a Bump,
     state: State<'a>,
@@ -845,18 +972,8 @@ fn numeric_negate_expression<'a, T>(
     let region = Region::new(start, expr.region.end());
 
     let new_expr = match expr.value {
-        Expr::Num(string) => {
-            let new_string =
-                unsafe { std::str::from_utf8_unchecked(&state.bytes()[..string.len() + 1]) };
-
-            Expr::Num(new_string)
-        }
-        Expr::Float(string) => {
-            let new_string =
-                unsafe { std::str::from_utf8_unchecked(&state.bytes()[..string.len() + 1]) };
-
-            Expr::Float(new_string)
-        }
+        Expr::Num(string) => Expr::Num(negated_literal(&state, string)),
+        Expr::Float(string) => Expr::Float(negated_literal(&state, string)),
         Expr::NonBase10Int {
             string,
             base,
@@ -987,7 +1104,7 @@ fn import_exposing<'a>() -> impl Parser<
     header::KeywordItem<
         'a,
         ImportExposingKeyword,
-        Collection<'a, Loc<Spaced<'a, header::ExposedName<'a>>>>,
+        Collection<'a, Loc<Spaced<'a, ImportExposedName<'a>>>>,
     >,
     EImport<'a>,
 > {
@@ -1000,7 +1117,13 @@ fn import_exposing<'a>() -> impl Parser<
         ),
         item: collection_trailing_sep_e(
             byte(b'[', EImport::ExposingListStart),
-            loc(import_exposed_name()),
+            header::import_exposed_entry(
+                EImport::ExposedName,
+                EImport::As,
+                EImport::IndentAs,
+                EImport::IndentAlias,
+                EImport::ExposedAliasCase,
+            ),
             byte(b',', EImport::ExposingListEnd),
             byte(b']', EImport::ExposingListEnd),
             Spaced::SpaceBefore
@@ -1008,15 +1131,6 @@ fn import_exposing<'a>() -> impl Parser<
     })
 }
 
-#[inline(always)]
-fn import_exposed_name<'a>(
-) -> impl Parser<'a, crate::ast::Spaced<'a, crate::header::ExposedName<'a>>, EImport<'a>> {
-    map(
-        specialize_err(|_, pos| EImport::ExposedName(pos), unqualified_ident()),
-        |n| Spaced::Item(crate::header::ExposedName::new(n)),
-    )
-}
-
 #[inline(always)]
 fn import_ingested_file_body<'a>() -> impl Parser<'a, ValueDef<'a>, EImport<'a>> {
     map(
@@ -1115,6 +1229,7 @@ fn parse_stmt_alias_or_opaque<'a>(
     arena: &'a Bump,
     state: State<'a>,
     min_indent: u32,
+    options: ExprParseOptions,
     expr_state: ExprState<'a>,
     kind: Loc<AliasOrOpaque>,
     spaces_after_operator: &'a [CommentOrNewline<'a>],
@@ -1213,12 +1328,39 @@ fn parse_stmt_alias_or_opaque<'a>(
                                 .with_spaces_before(spaces_after_operator, ann_type.region);
                         }
 
-                        let value_def = ValueDef::Annotation(Loc::at(expr_region, good), ann_type);
+                        let loc_pattern = Loc::at(expr_region, good);
+
+                        if kind.value == AliasOrOpaque::Alias {
+                            if let Some(after_arrow) = backpassing_arrow(arena, state.clone()) {
+                                // an annotated backpassing pattern, e.g. `x : I64 <- f`
+                                let (_, loc_body, state) =
+                                    space0_before_e(expr_start(options), EExpr::IndentEnd)
+                                        .parse(arena, after_arrow, min_indent)
+                                        .map_err(|(_, fail)| (MadeProgress, fail))?;
+
+                                let lhs = BackpassingPatterns {
+                                    patterns: arena.alloc([loc_pattern]),
+                                    ann: Some(ann_type),
+                                };
+
+                                let ret = Stmt::Backpassing(lhs, arena.alloc(loc_body));
+
+                                return Ok((MadeProgress, ret, state));
+                            }
+                        }
+
+                        let value_def = ValueDef::Annotation(loc_pattern, ann_type);
 
                         (Stmt::ValueDef(value_def), state)
                     }
                 }
             }
+            Err(_) if matches!(call.value.extract_spaces().item, Expr::Record(_)) => {
+                // a record value can't be annotated in place, only a record destructure
+                let fail = EExpr::RecordNotAPattern(call.region);
+
+                return Err((MadeProgress, fail));
+            }
             Err(_) => {
                 // this `:`/`:=` likely occurred inline; treat it as an invalid operator
                 let op = match kind.value {
@@ -1235,6 +1377,21 @@ fn parse_stmt_alias_or_opaque<'a>(
     Ok((MadeProgress, res, state))
 }
 
+/// If a `<-` follows on the same line, the state just after it.
+fn backpassing_arrow<'a>(arena: &'a Bump, state: State<'a>) -> Option<State<'a>> {
+    let (_, spaces, state) = space0_e(EExpr::IndentEnd).parse(arena, state, 0).ok()?;
+
+    if !spaces.is_empty() {
+        return None;
+    }
+
+    let (_, _, state) = two_bytes(b'<', b'-', EExpr::BackpassArrow)
+        .parse(arena, state, 0)
+        .ok()?;
+
+    Some(state)
+}
+
 mod ability {
     use parser::absolute_indented_seq;
 
@@ -1405,6 +1562,7 @@ fn finish_parsing_ability_def_help<'a>(
 /// For example, each of the following lines is a Stmt:
 /// - `foo bar` (Expr)
 /// - `foo, bar <- baz` (Backpassing)
+/// - `foo : Str <- baz` (Backpassing with a type annotation)
 /// - `Foo : [A, B, C]` (TypeDef)
 /// - `foo = \x -> x + 1` (ValueDef)
 ///
@@ -1413,7 +1571,7 @@ fn finish_parsing_ability_def_help<'a>(
 #[derive(Debug, Clone, Copy)]
 pub enum Stmt<'a> {
     Expr(Expr<'a>),
-    Backpassing(&'a [Loc<Pattern<'a>>], &'a Loc<Expr<'a>>),
+    Backpassing(BackpassingPatterns<'a>, &'a Loc<Expr<'a>>),
     TypeDef(TypeDef<'a>),
     ValueDef(ValueDef<'a>),
 }
@@ -1477,6 +1635,7 @@ fn parse_stmt_operator<'a>(
             loc_op,
             options,
             spaces_after_operator,
+            None,
         ),
         OperatorOrDef::Backpassing => parse_stmt_backpassing(
             arena,
@@ -1491,6 +1650,7 @@ fn parse_stmt_operator<'a>(
             arena,
             state,
             call_min_indent,
+            options,
             expr_state,
             loc_op.with_value(kind),
             spaces_after_operator.value,
@@ -1660,7 +1820,12 @@ fn parse_stmt_backpassing<'a>(
         }
     };
 
-    let ret = Stmt::Backpassing(arena.alloc([loc_pattern]), arena.alloc(loc_body));
+    let lhs = BackpassingPatterns {
+        patterns: arena.alloc([loc_pattern]),
+        ann: None,
+    };
+
+    let ret = Stmt::Backpassing(lhs, arena.alloc(loc_body));
 
     Ok((MadeProgress, ret, state))
 }
@@ -1722,7 +1887,12 @@ fn parse_stmt_multi_backpassing<'a>(
 
             let (_, loc_body, state) = parse_body.parse(arena, state, line_indent + 1)?;
 
-            let ret = Stmt::Backpassing(patterns.into_bump_slice(), arena.alloc(loc_body));
+            let lhs = BackpassingPatterns {
+                patterns: patterns.into_bump_slice(),
+                ann: None,
+            };
+
+            let ret = Stmt::Backpassing(lhs, arena.alloc(loc_body));
 
             Ok((MadeProgress, ret, state))
         }
@@ -1730,6 +1900,7 @@ fn parse_stmt_multi_backpassing<'a>(
 }
 
 /// We just saw the '=' operator of an assignment stmt. Continue parsing from there.
+#[allow(clippy::too_many_arguments)]
 fn parse_stmt_assignment<'a>(
     arena: &'a Bump,
     state: State<'a>,
@@ -1738,6 +1909,7 @@ fn parse_stmt_assignment<'a>(
     loc_op: Loc<OperatorOrDef>,
     options: ExprParseOptions,
     spaces_after_operator: Loc<&'a [CommentOrNewline]>,
+    as_suffix: Option<PatternAs<'a>>,
 ) -> ParseResult<'a, Stmt<'a>, EExpr<'a>> {
     let call = expr_state
         .validate_assignment_or_backpassing(arena, loc_op, EExpr::ElmStyleFunction)
@@ -1757,8 +1929,13 @@ fn parse_stmt_assignment<'a>(
                     !spaces_after_operator.value.is_empty(),
                 )?;
 
-                let alias =
-                    ValueDef::Body(arena.alloc(Loc::at(call.region, good)), arena.alloc(body));
+                let loc_pattern = Loc::at(call.region, good);
+                let loc_pattern = match as_suffix {
+                    Some(as_suffix) => with_pattern_as(arena, loc_pattern, &[], as_suffix),
+                    None => loc_pattern,
+                };
+
+                let alias = ValueDef::Body(arena.alloc(loc_pattern), arena.alloc(body));
 
                 (alias, state)
             }
@@ -1774,6 +1951,42 @@ fn parse_stmt_assignment<'a>(
     Ok((MadeProgress, Stmt::ValueDef(value_def), state))
 }
 
+/// We just parsed an expression followed by `as` and a name, like `{ x } as point`.
+/// That can only be the pattern of an assignment, so an `=` must come next.
+fn parse_stmt_pattern_as<'a>(
+    arena: &'a Bump,
+    state: State<'a>,
+    min_indent: u32,
+    call_min_indent: u32,
+    mut expr_state: ExprState<'a>,
+    options: ExprParseOptions,
+    as_suffix: PatternAs<'a>,
+) -> ParseResult<'a, Stmt<'a>, EExpr<'a>> {
+    let (_, _, state) = space0_e(EExpr::IndentEquals).parse(arena, state, min_indent)?;
+    let equals_pos = state.pos();
+
+    match loc(operator()).parse(arena, state, min_indent) {
+        Ok((_, loc_op, state)) if loc_op.value == OperatorOrDef::Assignment => {
+            let (_, spaces_after_operator, state) =
+                loc_space0_e(EExpr::IndentEnd).parse(arena, state, min_indent)?;
+
+            expr_state.consume_spaces(arena);
+
+            parse_stmt_assignment(
+                arena,
+                state,
+                call_min_indent,
+                expr_state,
+                loc_op,
+                options,
+                spaces_after_operator,
+                Some(as_suffix),
+            )
+        }
+        _ => Err((MadeProgress, EExpr::Equals(equals_pos))),
+    }
+}
+
 /// We just saw a unary negation operator, and now we need to parse the expression.
 #[allow(clippy::too_many_arguments)]
 fn parse_negated_term<'a>(
@@ -1924,10 +2137,26 @@ fn parse_stmt_after_apply<'a>(
             } else if options.check_for_arrow && state.bytes().starts_with(b"->") {
                 Err((MadeProgress, EExpr::BadOperator("->", state.pos())))
             } else {
-                let expr = parse_expr_final(expr_state, arena);
+                match pattern_as().parse(arena, state.clone(), min_indent) {
+                    Ok((_, as_suffix, state)) => parse_stmt_pattern_as(
+                        arena,
+                        state,
+                        min_indent,
+                        call_min_indent,
+                        expr_state,
+                        options,
+                        as_suffix,
+                    ),
+                    Err((MadeProgress, fail)) => {
+                        Err((MadeProgress, EExpr::Pattern(arena.alloc(fail), state.pos())))
+                    }
+                    Err((NoProgress, _)) => {
+                        let expr = parse_expr_final(expr_state, arena);
 
-                // roll back space parsing
-                Ok((MadeProgress, Stmt::Expr(expr), initial_state))
+                        // roll back space parsing
+                        Ok((MadeProgress, Stmt::Expr(expr), initial_state))
+                    }
+                }
             }
         }
     }
@@ -2036,6 +2265,7 @@ pub fn loc_expr_block<'a>(
             let options = ExprParseOptions {
                 accept_multi_backpassing,
                 check_for_arrow: true,
+                concat_strs_across_lines: true,
             };
 
             let (_, loc_first_space, state) =
@@ -2062,6 +2292,7 @@ pub fn loc_expr<'a>(accept_multi_backpassing: bool) -> impl Parser<'a, Loc<Expr<
         expr_start(ExprParseOptions {
             accept_multi_backpassing,
             check_for_arrow: true,
+            concat_strs_across_lines: true,
         }),
         EExpr::IndentEnd,
     )
@@ -2181,6 +2412,7 @@ fn expr_to_pattern_help<'a>(arena: &'a Bump, expr: &Expr<'a>) -> Result<Pattern<
         | Expr::RecordUpdate { .. }
         | Expr::RecordUpdater(_)
         | Expr::UnaryOp(_, _)
+        | Expr::Spread(_)
         | Expr::TrySuffix { .. }
         | Expr::Crash
         | Expr::RecordBuilder { .. } => return Err(()),
@@ -2202,6 +2434,19 @@ fn expr_to_pattern_help<'a>(arena: &'a Bump, expr: &Expr<'a>) -> Result<Pattern<
     Ok(pat)
 }
 
+/// Whether a converted pattern applies a lowercase name, like `f y`.
+/// Only tags and opaque types can be applied in a pattern.
+fn is_call_pattern(pattern: &Pattern<'_>) -> bool {
+    match pattern {
+        Pattern::Apply(loc_fn, _) => matches!(
+            loc_fn.value,
+            Pattern::Identifier { .. } | Pattern::QualifiedIdentifier { .. }
+        ),
+        Pattern::SpaceBefore(inner, _) | Pattern::SpaceAfter(inner, _) => is_call_pattern(inner),
+        _ => false,
+    }
+}
+
 fn assigned_expr_field_to_pattern_help<'a>(
     arena: &'a Bump,
     assigned_field: &AssignedField<'a, Expr<'a>>,
@@ -2210,6 +2455,12 @@ fn assigned_expr_field_to_pattern_help<'a>(
     Ok(match assigned_field {
         AssignedField::RequiredValue(name, spaces, value) => {
             let pattern = expr_to_pattern_help(arena, &value.value)?;
+
+            // `{ x: f y }` calls a function, so the record must be a value
+            if is_call_pattern(&pattern) {
+                return Err(());
+            }
+
             let result = arena.alloc(Loc {
                 region: value.region,
                 value: pattern,
@@ -2265,6 +2516,7 @@ pub fn parse_top_level_defs<'a>(
         ExprParseOptions {
             accept_multi_backpassing: true,
             check_for_arrow: true,
+            concat_strs_across_lines: true,
         },
         0,
         loc_first_space,
@@ -2279,10 +2531,12 @@ pub fn parse_top_level_defs<'a>(
         stmts_to_defs(&stmts, output, false, arena).map_err(|e| (MadeProgress, e))?;
 
     if let Some(expr) = last_expr {
-        return Err((
-            MadeProgress,
-            EExpr::UnexpectedTopLevelExpr(expr.region.start()),
-        ));
+        let problem = match double_equals_def(&expr.value) {
+            Some(region) => EExpr::DoubleEqualsDef(region),
+            None => EExpr::UnexpectedTopLevelExpr(expr.region.start()),
+        };
+
+        return Err((MadeProgress, problem));
     }
 
     if output.tags.len() > existing_len {
@@ -2290,6 +2544,18 @@ pub fn parse_top_level_defs<'a>(
         let last = output.tags.len() - 1;
         debug_assert!(output.space_after[last].is_empty() || after.is_empty());
         output.space_after[last] = after;
+    } else {
+        // There were no defs, so nothing picked up the leading spaces.
+        // Keep any comments in them, so formatting the module doesn't drop them.
+        let spaces = loc_first_space.value.iter().chain(last_space.iter());
+
+        if let Some(last) = output.tags.len().checked_sub(1) {
+            let after = slice_extend_new(&mut output.spaces, spaces.copied());
+            debug_assert!(output.space_after[last].is_empty() || after.is_empty());
+            output.space_after[last] = after;
+        } else if spaces.clone().any(|space| space.is_comment()) {
+            output.spaces.extend(spaces.copied());
+        }
     }
 
     Ok((MadeProgress, output, state))
@@ -2320,7 +2586,7 @@ fn closure_help<'a>(options: ExprParseOptions) -> impl Parser<'a, Expr<'a>, EClo
                 ),
                 skip_first(
                     // Parse the -> which separates params from body
-                    two_bytes(b'-', b'>', EClosure::Arrow),
+                    closure_arrow(),
                     // Parse the body
                     block(options, true, EClosure::IndentBody, EClosure::Body),
                 ),
@@ -2334,6 +2600,18 @@ fn closure_help<'a>(options: ExprParseOptions) -> impl Parser<'a, Expr<'a>, EClo
     )
 }
 
+fn closure_arrow<'a>() -> impl Parser<'a, (), EClosure<'a>> {
+    move |arena: &'a Bump, state: State<'a>, min_indent: u32| {
+        match two_bytes(b'-', b'>', EClosure::Arrow).parse(arena, state.clone(), min_indent) {
+            // e.g. `\Red | Green -> ...`; or-patterns are only allowed in `when` branches
+            Err((NoProgress, _)) if state.bytes().first() == Some(&b'|') => {
+                Err((NoProgress, EClosure::OrPattern(state.pos())))
+            }
+            result => result,
+        }
+    }
+}
+
 mod when {
     use parser::indented_seq_skip_first;
 
@@ -2342,28 +2620,53 @@ mod when {
 
     /// Parser for when expressions.
     pub fn when_expr_help<'a>(options: ExprParseOptions) -> impl Parser<'a, Expr<'a>, EWhen<'a>> {
-        map_with_arena(
-            and(
-                indented_seq_skip_first(
-                    parser::keyword(keyword::WHEN, EWhen::When),
-                    space0_around_e_no_after_indent_check(
-                        specialize_err_ref(EWhen::Condition, expr_start(options)),
-                        EWhen::IndentCondition,
-                    )
+        move |arena: &'a Bump, state: State<'a>, min_indent: u32| {
+            let when_start = state.pos();
+            let when_indent = state.line_indent();
+
+            let (_, loc_condition, state) = indented_seq_skip_first(
+                parser::keyword(keyword::WHEN, EWhen::When),
+                space0_around_e_no_after_indent_check(
+                    specialize_err_ref(EWhen::Condition, expr_start(options)),
+                    EWhen::IndentCondition,
                 ),
-                // Note that we allow the `is` to be at any indent level, since this doesn't introduce any
-                // ambiguity. The formatter will fix it up.
-                //
-                // We require that branches are indented relative to the line containing the `is`.
-                indented_seq_skip_first(
-                    parser::keyword(keyword::IS, EWhen::Is),
-                    branches(options)
-                )
-            ),
-            move |arena: &'a Bump, (loc_condition, branches): (Loc<Expr<'a>>, Vec<'a, &'a WhenBranch<'a>>)| {
-                Expr::When(arena.alloc(loc_condition), branches.into_bump_slice())
+            )
+            .parse(arena, state, min_indent)?;
+
+            // Note that we allow the `is` to be at any indent level, as long as it isn't outdented
+            // past the line containing the `when`. This doesn't introduce any ambiguity, and the
+            // formatter will fix it up.
+            if state.column() < when_indent
+                && peek(parser::keyword(keyword::IS, EWhen::Is))
+                    .parse(arena, state.clone(), 0)
+                    .is_ok()
+            {
+                return Err((MadeProgress, EWhen::IndentIs(state.pos())));
             }
-        ).trace("when")
+
+            // We require that branches are indented relative to the line containing the `is`.
+            let is_indent = state.line_indent();
+
+            let (_, _, state) =
+                parser::keyword(keyword::IS, EWhen::Is).parse(arena, state, is_indent)?;
+
+            let is_end = state.pos();
+
+            let (_, branches, state) = branches(options)
+                .parse(arena, state, is_indent + 1)
+                .map_err(|(progress, fail)| match progress {
+                    // Not even the first branch's pattern got started
+                    NoProgress => (
+                        MadeProgress,
+                        EWhen::NoBranches(Region::new(when_start, is_end)),
+                    ),
+                    MadeProgress => (MadeProgress, fail),
+                })?;
+
+            let expr = Expr::When(arena.alloc(loc_condition), branches.into_bump_slice());
+
+            Ok((MadeProgress, expr, state))
+        }
     }
 
     fn branches<'a>(
@@ -2383,9 +2686,11 @@ mod when {
 
             let original_indent = pattern_indent_level;
 
-            // Parse the first "->" and the expression after it.
-            let (_, loc_first_expr, mut state) =
-                branch_result(original_indent + 1).parse(arena, state, original_indent + 1)?;
+            // Parse the first "->" and the expression after it. The first branch's patterns
+            // are already parsed, so any failure from here on made progress.
+            let (_, loc_first_expr, mut state) = branch_result(original_indent + 1)
+                .parse(arena, state, original_indent + 1)
+                .map_err(|(_, fail)| (MadeProgress, fail))?;
 
             // Record this as the first branch, then optionally parse additional branches.
             branches.push(arena.alloc(WhenBranch {
@@ -2448,27 +2753,48 @@ mod when {
             check_for_arrow: false,
             ..options
         };
-        and(
-            branch_alternatives_help(pattern_indent_level),
-            one_of![
-                map(
-                    skip_first(
-                        parser::keyword(keyword::IF, EWhen::IfToken),
-                        // TODO we should require space before the expression but not after
-                        space0_around_ee(
-                            specialize_err_ref(
-                                EWhen::IfGuard,
-                                increment_min_indent(expr_start(options))
-                            ),
-                            EWhen::IndentIfGuard,
-                            EWhen::IndentArrow,
-                        )
-                    ),
-                    Some
-                ),
-                |_, s, _| Ok((NoProgress, None, s))
-            ],
-        )
+        move |arena, state: State<'a>, min_indent: u32| {
+            let (_, (indent_column, loc_patterns), state) =
+                branch_alternatives_help(pattern_indent_level).parse(arena, state, min_indent)?;
+
+            // The guard belongs to this branch, so it must be indented past the branch's
+            // patterns, regardless of the indentation of the `when` itself.
+            let guard_indent = indent_column + 1;
+
+            let (_, loc_guard, state) =
+                optional(if_guard(options)).parse(arena, state, guard_indent)?;
+
+            Ok((
+                MadeProgress,
+                ((indent_column, loc_patterns), loc_guard),
+                state,
+            ))
+        }
+    }
+
+    /// Parse the `if <condition>` guard of a `when` branch.
+    fn if_guard<'a>(options: ExprParseOptions) -> impl Parser<'a, Loc<Expr<'a>>, EWhen<'a>> {
+        move |arena, state: State<'a>, min_indent: u32| {
+            let guard_start = state.pos();
+            let if_column = state.column();
+
+            let (_, _, state) =
+                parser::keyword(keyword::IF, EWhen::IfToken).parse(arena, state, min_indent)?;
+
+            // an `if` on its own line must still be indented past the patterns
+            if if_column < min_indent {
+                return Err((MadeProgress, EWhen::IndentIfGuard(guard_start)));
+            }
+
+            // TODO we should require space before the expression but not after
+            space0_around_ee(
+                specialize_err_ref(EWhen::IfGuard, expr_start(options)),
+                EWhen::IndentIfGuard,
+                EWhen::IndentArrow,
+            )
+            .parse(arena, state, min_indent)
+            .map_err(|(_, fail)| (MadeProgress, fail))
+        }
     }
 
     fn error_on_arrow<'a, T, E: 'a>(f: impl Fn(Position) -> E) -> impl Parser<'a, T, E> {
@@ -2521,7 +2847,21 @@ mod when {
                         }
                         Some(wanted) if state.column() < wanted => {
                             let indent = wanted - state.column();
-                            Err((NoProgress, EWhen::PatternAlignment(indent, state.pos())))
+                            let fail = EWhen::PatternAlignment(indent, state.pos());
+
+                            // A branch that is still inside this `when`, but not aligned with
+                            // the other branches, is a mistake rather than the end of the `when`
+                            if state.column() >= min_indent && starts_with_branch(arena, state) {
+                                Err((MadeProgress, fail))
+                            } else {
+                                Err((NoProgress, fail))
+                            }
+                        }
+                        None if state.column() < min_indent
+                            && !starts_with_branch(arena, state.clone()) =>
+                        {
+                            // whatever comes after the `is` is not indented past it, nor a branch
+                            Err((NoProgress, EWhen::IndentPattern(state.pos())))
                         }
                         _ => {
                             let pattern_indent =
@@ -2530,8 +2870,7 @@ mod when {
                             // parentheses around patterns
                             let pattern_indent_column = state.column();
 
-                            let parser =
-                                sep_by1(byte(b'|', EWhen::Bar), branch_single_alternative());
+                            let parser = sep_by1(alternative_bar(), branch_single_alternative());
 
                             match parser.parse(arena, state.clone(), pattern_indent) {
                                 Err((MadeProgress, fail)) => Err((MadeProgress, fail)),
@@ -2560,11 +2899,36 @@ mod when {
         }
     }
 
+    /// The `|` between the alternatives of a branch. A `||` is a mistake rather than the end of
+    /// the patterns, so it gets its own error.
+    fn alternative_bar<'a>() -> impl Parser<'a, (), EWhen<'a>> {
+        move |arena: &'a Bump, state: State<'a>, min_indent: u32| {
+            if state.bytes().starts_with(b"||") {
+                let region = Region::new(state.pos(), state.pos().bump_column(2));
+
+                Err((MadeProgress, EWhen::DoubleBar(region)))
+            } else {
+                byte(b'|', EWhen::Bar).parse(arena, state, min_indent)
+            }
+        }
+    }
+
+    /// Whether the input starts with the patterns of a branch, followed by its `->`
+    fn starts_with_branch<'a>(arena: &'a Bump, state: State<'a>) -> bool {
+        let parser = peek(skip_second(
+            sep_by1(alternative_bar(), branch_single_alternative()),
+            two_bytes(b'-', b'>', EWhen::Arrow),
+        ));
+
+        parser.parse(arena, state, 0).is_ok()
+    }
+
     /// Parsing the righthandside of a branch in a when conditional.
     fn branch_result<'a>(indent: u32) -> impl Parser<'a, Loc<Expr<'a>>, EWhen<'a>> {
         let options = ExprParseOptions {
             accept_multi_backpassing: true,
             check_for_arrow: true,
+            concat_strs_across_lines: true,
         };
         move |arena, state, _min_indent| {
             skip_first(
@@ -2580,6 +2944,7 @@ fn if_branch<'a>() -> impl Parser<'a, (Loc<Expr<'a>>, Loc<Expr<'a>>), EIf<'a>> {
     let options = ExprParseOptions {
         accept_multi_backpassing: true,
         check_for_arrow: true,
+        concat_strs_across_lines: true,
     };
     skip_second(
         and(
@@ -2702,15 +3067,25 @@ fn if_expr_help<'a>(options: ExprParseOptions) -> impl Parser<'a, Expr<'a>, EIf<
         let mut branches = Vec::with_capacity_in(1, arena);
 
         let mut loop_state = state;
+        let mut spaces_before_if: &'a [CommentOrNewline<'a>] = &[];
 
         let state_final_else = loop {
             let (_, (cond, then_branch), state) =
                 if_branch().parse(arena, loop_state, min_indent)?;
 
+            // spaces between the previous `else` and this `if` go before this branch's condition
+            let cond = match cond.value {
+                _ if spaces_before_if.is_empty() => cond,
+                Expr::SpaceBefore(inner, spaces) => cond.with_value(Expr::SpaceBefore(
+                    inner,
+                    merge_spaces(arena, spaces_before_if, spaces),
+                )),
+                _ => cond.with_value(Expr::SpaceBefore(arena.alloc(cond.value), spaces_before_if)),
+            };
+
             branches.push((cond, then_branch));
 
             // try to parse another `if`
-            // NOTE this drops spaces between the `else` and the `if`
             let optional_if = and(
                 backtrackable(space0_e(EIf::IndentIf)),
                 parser::keyword(keyword::IF, EIf::If),
@@ -2718,7 +3093,8 @@ fn if_expr_help<'a>(options: ExprParseOptions) -> impl Parser<'a, Expr<'a>, EIf<
 
             match optional_if.parse(arena, state.clone(), min_indent) {
                 Err((_, _)) => break state,
-                Ok((_, _, state)) => {
+                Ok((_, (spaces, ()), state)) => {
+                    spaces_before_if = spaces;
                     loop_state = state;
                     continue;
                 }
@@ -2914,6 +3290,15 @@ fn parse_stmt_seq<'a, E: SpaceProblem + 'a>(
             break;
         }
 
+        if !stmts.is_empty() && starts_with_binop(arena, &state) {
+            // a leading operator that is not indented enough to continue the previous line,
+            // rather than a new statement that is missing its left operand
+            return Err((
+                MadeProgress,
+                wrap_error(arena.alloc(EExpr::IndentOperator(state.pos())), state.pos()),
+            ));
+        }
+
         let loc_stmt = match specialize_err_ref(wrap_error, stmt_start(options, last_space.region))
             .parse(arena, state.clone(), min_indent)
         {
@@ -2952,6 +3337,16 @@ fn parse_stmt_seq<'a, E: SpaceProblem + 'a>(
                         break;
                     }
 
+                    if let Some(kw) = unparenthesized_keyword_arg(&new_state) {
+                        return Err((
+                            MadeProgress,
+                            wrap_error(
+                                arena.alloc(EExpr::UnparenthesizedKeywordArg(kw, new_state.pos())),
+                                state.pos(),
+                            ),
+                        ));
+                    }
+
                     return Err((
                         MadeProgress,
                         wrap_error(arena.alloc(EExpr::BadExprEnd(state.pos())), state.pos()),
@@ -2968,6 +3363,28 @@ fn parse_stmt_seq<'a, E: SpaceProblem + 'a>(
     Ok((MadeProgress, stmts, state))
 }
 
+/// Check if the next token is a binary operator that could continue an expression.
+/// A `-` is not considered, because it can also start a negated number or expression.
+fn starts_with_binop<'a>(arena: &'a Bump, state: &State<'a>) -> bool {
+    matches!(
+        operator().parse(arena, state.clone(), 0),
+        Ok((_, OperatorOrDef::BinOp(op), _)) if op != BinOp::Minus
+    )
+}
+
+/// Check if an `if` or `when` keyword follows on the same line, which means it
+/// was most likely meant as a function argument and is missing its parentheses.
+fn unparenthesized_keyword_arg(state: &State<'_>) -> Option<&'static str> {
+    [keyword::IF, keyword::WHEN].into_iter().find(|kw| {
+        let bytes = state.bytes();
+        bytes.starts_with(kw.as_bytes())
+            && !matches!(
+                bytes.get(kw.len()),
+                Some(b) if b.is_ascii_alphanumeric() || *b == b'_'
+            )
+    })
+}
+
 /// Check if the current byte is a terminator for a sequence of statements
 fn at_terminator(state: &State<'_>) -> bool {
     matches!(
@@ -3037,15 +3454,49 @@ fn stmts_to_expr<'a>(
                     loc_stmt.region.start(),
                 ));
             }
-            Stmt::Backpassing(..) | Stmt::TypeDef(_) | Stmt::ValueDef(_) => {
-                return Err(EExpr::IndentEnd(loc_stmt.region.end()))
+            Stmt::TypeDef(_) | Stmt::ValueDef(_) => {
+                // a lone def is missing its final expression, just like a series of defs
+                return Err(EExpr::DefMissingFinalExpr(loc_stmt.region.end()));
             }
+            Stmt::Backpassing(..) => return Err(EExpr::IndentEnd(loc_stmt.region.end())),
         };
 
         Ok(loc_stmt.with_value(expr))
     }
 }
 
+/// The error for a statement that follows the final expression of a block.
+/// If that expression looks like a def written with `==`, point at the `==` instead.
+fn stmt_after_expr<'a>(last_expr: &Loc<Expr<'a>>, pos: Position) -> EExpr<'a> {
+    match double_equals_def(&last_expr.value) {
+        Some(region) => EExpr::DoubleEqualsDef(region),
+        None => EExpr::StmtAfterExpr(pos),
+    }
+}
+
+/// The region of the `==` in an expression like `x == 5`, which was probably meant to be `x = 5`.
+fn double_equals_def(expr: &Expr<'_>) -> Option<Region> {
+    fn without_spaces<'a, 'b>(mut expr: &'b Expr<'a>) -> &'b Expr<'a> {
+        while let Expr::SpaceBefore(inner, _) | Expr::SpaceAfter(inner, _) = expr {
+            expr = inner;
+        }
+
+        expr
+    }
+
+    match without_spaces(expr) {
+        Expr::BinOps([(lhs, op)], _) if op.value == BinOp::Equals => {
+            match without_spaces(&lhs.value) {
+                Expr::Var {
+                    module_name: "", ..
+                } => Some(op.region),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 /// Convert a sequence of `Stmt` into a Defs and an optional final expression.
 /// Future refactoring opportunity: push this logic directly into where we're
 /// parsing the statements.
@@ -3069,8 +3520,8 @@ fn stmts_to_defs<'a>(
                         &[],
                     );
                 } else {
-                    if last_expr.is_some() {
-                        return Err(EExpr::StmtAfterExpr(sp_stmt.item.region.start()));
+                    if let Some(last_expr) = last_expr {
+                        return Err(stmt_after_expr(&last_expr, sp_stmt.item.region.start()));
                     }
 
                     let e = if sp_stmt.before.is_empty() {
@@ -3082,9 +3533,9 @@ fn stmts_to_defs<'a>(
                     last_expr = Some(sp_stmt.item.with_value(e));
                 }
             }
-            Stmt::Backpassing(pats, call) => {
-                if last_expr.is_some() {
-                    return Err(EExpr::StmtAfterExpr(sp_stmt.item.region.start()));
+            Stmt::Backpassing(lhs, call) => {
+                if let Some(last_expr) = last_expr {
+                    return Err(stmt_after_expr(&last_expr, sp_stmt.item.region.start()));
                 }
 
                 if i + 1 >= stmts.len() {
@@ -3093,7 +3544,7 @@ fn stmts_to_defs<'a>(
 
                 let rest = stmts_to_expr(&stmts[i + 1..], arena)?;
 
-                let e = Expr::Backpassing(arena.alloc(pats), arena.alloc(call), arena.alloc(rest));
+                let e = Expr::Backpassing(arena.alloc(lhs), arena.alloc(call), arena.alloc(rest));
 
                 let e = if sp_stmt.before.is_empty() {
                     e
@@ -3110,8 +3561,8 @@ fn stmts_to_defs<'a>(
             }
 
             Stmt::TypeDef(td) => {
-                if last_expr.is_some() {
-                    return Err(EExpr::StmtAfterExpr(sp_stmt.item.region.start()));
+                if let Some(last_expr) = last_expr {
+                    return Err(stmt_after_expr(&last_expr, sp_stmt.item.region.start()));
                 }
 
                 if let (
@@ -3165,8 +3616,8 @@ fn stmts_to_defs<'a>(
                 }
             }
             Stmt::ValueDef(vd) => {
-                if last_expr.is_some() {
-                    return Err(EExpr::StmtAfterExpr(sp_stmt.item.region.start()));
+                if let Some(last_expr) = last_expr {
+                    return Err(stmt_after_expr(&last_expr, sp_stmt.item.region.start()));
                 }
 
                 // NOTE: it shouldn't be necessary to convert ValueDef::Dbg into an expr, but
@@ -3358,23 +3809,250 @@ fn ident_to_expr<'a>(arena: &'a Bump, src: Ident<'a>) -> Expr<'a> {
     }
 }
 
+/// The closing brace of a list or record literal.
+///
+/// The elements have already consumed every comma that follows an element, so a comma here
+/// has no element before it, like the leading comma in `[, 1]` or the second one in `[1, , 2]`.
+fn collection_end<'a, E: 'a>(
+    closing_brace: u8,
+    to_error: fn(Position) -> E,
+    unexpected_comma: fn(Region) -> E,
+) -> impl Parser<'a, (), E> {
+    move |arena: &'a Bump, state: State<'a>, min_indent: u32| {
+        if state.bytes().starts_with(b",") {
+            let region = Region::new(state.pos(), state.pos().bump_column(1));
+
+            Err((NoProgress, unexpected_comma(region)))
+        } else {
+            byte(closing_brace, to_error).parse(arena, state, min_indent)
+        }
+    }
+}
+
+/// The elements of a list are separated either by commas, or by putting each one on its own
+/// line. Which one is decided by the first separator, and the other is then an error.
 fn list_literal_help<'a>() -> impl Parser<'a, Expr<'a>, EList<'a>> {
-    map_with_arena(
-        collection_trailing_sep_e(
+    then(
+        loc(collection_trailing_sep_e(
             byte(b'[', EList::Open),
-            specialize_err_ref(EList::Expr, loc_expr(false)),
-            byte(b',', EList::End),
-            byte(b']', EList::End),
+            specialize_err_ref(EList::Expr, loc_collection_element(loc_list_element())),
+            one_of![byte(b',', EList::End), newline_separator(EList::End)],
+            one_of![
+                malformed_spread(EList::Expr),
+                collection_end(b']', EList::End, EList::UnexpectedComma)
+            ],
             Expr::SpaceBefore,
-        ),
-        |arena, elements: Collection<'a, _>| {
-            let elements = elements.ptrify_items(arena);
-            Expr::List(elements)
+        )),
+        |arena, state: State<'a>, progress, loc_elements: Loc<Collection<'a, _>>| {
+            let src = state.original_bytes();
+
+            if let Some(pos) = mixed_list_separator(src, loc_elements.region, &loc_elements.value) {
+                return Err((MadeProgress, EList::MixedSeparators(pos)));
+            }
+
+            if let Some(pos) = misaligned_list_element(src, &loc_elements.value) {
+                return Err((MadeProgress, EList::MisalignedElement(pos)));
+            }
+
+            let elements = loc_elements.value.ptrify_items(arena);
+
+            Ok((progress, Expr::List(elements), state))
         },
     )
     .trace("list_literal")
 }
 
+fn loc_list_element<'a>() -> impl Parser<'a, Loc<Expr<'a>>, EExpr<'a>> {
+    space0_before_e(
+        expr_start(ExprParseOptions {
+            accept_multi_backpassing: false,
+            check_for_arrow: true,
+            concat_strs_across_lines: false,
+        }),
+        EExpr::IndentEnd,
+    )
+}
+
+/// Separates two collection elements without a comma, when the next one starts a new line.
+///
+/// The elements must also line up with each other, but the first element isn't known here,
+/// so that's checked by [misaligned_list_element] once the whole list is parsed.
+fn newline_separator<'a, E: 'a>(to_error: fn(Position) -> E) -> impl Parser<'a, (), E> {
+    move |_arena: &'a Bump, state: State<'a>, _min_indent: u32| {
+        if state.column() == state.line_indent() {
+            Ok((NoProgress, (), state))
+        } else {
+            Err((NoProgress, to_error(state.pos())))
+        }
+    }
+}
+
+/// The position of the first separator in a list that doesn't match the list's first one: a
+/// comma in a newline-separated list, or the start of an element that's missing its comma.
+fn mixed_list_separator<'a>(
+    src: &[u8],
+    list_region: Region,
+    elements: &Collection<'a, Loc<Expr<'a>>>,
+) -> Option<Position> {
+    let items = elements.items;
+    let mut uses_commas = None;
+
+    for (index, element) in items.iter().enumerate() {
+        let gap_end = match items.get(index + 1) {
+            Some(next) => next.region.start(),
+            // the gap before the closing `]`, which only matters if it has a trailing comma
+            None => list_region.end().sub(1),
+        };
+        let comma = comma_between(src, element.region.end(), gap_end);
+
+        if items.get(index + 1).is_none() && comma.is_none() {
+            break;
+        }
+
+        match (*uses_commas.get_or_insert(comma.is_some()), comma) {
+            (false, Some(pos)) => return Some(pos),
+            (true, None) => return Some(gap_end),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// The start of the first element in a newline-separated list that isn't in the same column as
+/// the first element, e.g. the `2` in
+///
+/// ```roc
+/// [
+///     1
+///   2
+/// ]
+/// ```
+fn misaligned_list_element<'a>(
+    src: &[u8],
+    elements: &Collection<'a, Loc<Expr<'a>>>,
+) -> Option<Position> {
+    let (first, rest) = elements.items.split_first()?;
+    let second = rest.first()?;
+
+    // Comma-separated elements can go wherever they like
+    if comma_between(src, first.region.end(), second.region.start()).is_some() {
+        return None;
+    }
+
+    let column = |pos: Position| {
+        let line_start = src[..pos.offset as usize]
+            .iter()
+            .rposition(|byte| *byte == b'\n')
+            .map_or(0, |index| index + 1);
+
+        pos.offset as usize - line_start
+    };
+    let first_column = column(first.region.start());
+
+    rest.iter()
+        .map(|element| element.region.start())
+        .find(|pos| column(*pos) != first_column)
+}
+
+/// The position of the comma between two elements, skipping over any comments.
+fn comma_between(src: &[u8], start: Position, end: Position) -> Option<Position> {
+    let mut in_comment = false;
+
+    for offset in start.offset..end.offset {
+        match src[offset as usize] {
+            b'#' => in_comment = true,
+            b'\n' => in_comment = false,
+            b',' if !in_comment => return Some(Position::new(offset)),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// A list or tuple element, which may be a spread like the `..rest` in `[a, ..rest]`.
+fn loc_collection_element<'a, P>(element: P) -> impl Parser<'a, Loc<Expr<'a>>, EExpr<'a>>
+where
+    P: Parser<'a, Loc<Expr<'a>>, EExpr<'a>>,
+{
+    one_of![loc_spread(), element]
+}
+
+fn loc_spread<'a>() -> impl Parser<'a, Loc<Expr<'a>>, EExpr<'a>> {
+    let options = ExprParseOptions {
+        accept_multi_backpassing: false,
+        check_for_arrow: true,
+        concat_strs_across_lines: true,
+    };
+
+    (move |arena: &'a Bump, state: State<'a>, min_indent: u32| {
+        let start = state.pos();
+
+        if !state.bytes().starts_with(b"..") {
+            return Err((NoProgress, EExpr::Start(start)));
+        }
+
+        let state = state.advance(2);
+
+        if state.bytes().starts_with(b".") {
+            let region = Region::new(start, state.pos().bump_column(1));
+
+            return Err((MadeProgress, EExpr::BadSpread(ESpread::ThreeDots, region)));
+        }
+
+        let (_, loc_value, state) = match loc_term(options).parse(arena, state.clone(), min_indent)
+        {
+            Ok(ok) => ok,
+            Err((NoProgress, _)) => {
+                let region = Region::new(start, state.pos());
+
+                return Err((
+                    MadeProgress,
+                    EExpr::BadSpread(ESpread::MissingValue, region),
+                ));
+            }
+            Err(fail) => return Err(fail),
+        };
+
+        let region = Region::new(start, state.pos());
+
+        // Anything else before the end of the element means the spread was meant to
+        // cover more than a single term, like `..f x` or `..xs ++ ys`
+        let rest = state.bytes();
+        let gap = rest.iter().take_while(|&&b| b == b' ').count();
+
+        if !matches!(
+            rest.get(gap),
+            None | Some(b',' | b']' | b')' | b'\n' | b'\r' | b'#')
+        ) {
+            return Err((MadeProgress, EExpr::BadSpread(ESpread::NeedsParens, region)));
+        }
+
+        Ok((
+            MadeProgress,
+            Loc::at(region, Expr::Spread(arena.alloc(loc_value))),
+            state,
+        ))
+    })
+    .trace("spread")
+}
+
+/// A malformed spread after a comma is taken for a trailing comma, so report it where
+/// we expected the closing bracket instead, e.g. `[1, ..]`.
+fn malformed_spread<'a, E: 'a>(
+    to_error: fn(&'a EExpr<'a>, Position) -> E,
+) -> impl Parser<'a, (), E> {
+    move |arena: &'a Bump, state: State<'a>, min_indent: u32| {
+        let pos = state.pos();
+
+        match loc_spread().parse(arena, state, min_indent) {
+            Err((MadeProgress, fail)) => Err((MadeProgress, to_error(arena.alloc(fail), pos))),
+            _ => Err((NoProgress, to_error(arena.alloc(EExpr::Start(pos)), pos))),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum RecordField<'a> {
     RequiredValue(Loc<&'a str>, &'a [CommentOrNewline<'a>], &'a Loc<Expr<'a>>),
@@ -3541,44 +4219,77 @@ fn record_prefix_identifier<'a>() -> impl Parser<'a, Expr<'a>, ERecord<'a>> {
     )
 }
 
+/// The `&` of a record update or the `<-` of a record builder. A `&&` is most likely a typo
+/// for a record update, so it gets its own error instead of being rolled back.
+fn record_prefix_operator<'a>() -> impl Parser<'a, RecordHelpPrefix, ERecord<'a>> {
+    move |arena: &'a Bump, state: State<'a>, min_indent: u32| {
+        if state.bytes().starts_with(b"&&") {
+            let region = Region::new(state.pos(), state.pos().bump_column(2));
+
+            return Err((MadeProgress, ERecord::DoubleAmpersand(region)));
+        }
+
+        let parser = map(
+            either(
+                byte(b'&', ERecord::Ampersand),
+                two_bytes(b'<', b'-', ERecord::Arrow),
+            ),
+            |output| match output {
+                Either::First(()) => RecordHelpPrefix::Update,
+                Either::Second(()) => RecordHelpPrefix::Mapper,
+            },
+        );
+
+        backtrackable(parser).parse(arena, state, min_indent)
+    }
+}
+
 struct RecordHelp<'a> {
     prefix: Option<(Loc<Expr<'a>>, RecordHelpPrefix)>,
     fields: Collection<'a, Loc<RecordField<'a>>>,
 }
 
 fn record_help<'a>() -> impl Parser<'a, RecordHelp<'a>, ERecord<'a>> {
-    between(
-        byte(b'{', ERecord::Open),
-        reset_min_indent(record!(RecordHelp {
-            // You can optionally have an identifier followed by an '&' to
-            // make this a record update, e.g. { Foo.user & username: "blah" }.
-            prefix: optional(backtrackable(and(
-                // We wrap the ident in an Expr here,
-                // so that we have a Spaceable value to work with,
-                // and then in canonicalization verify that it's an Expr::Var
-                // (and not e.g. an `Expr::Access`) and extract its string.
-                spaces_around(loc(record_prefix_identifier())),
-                map_with_arena(
-                    either(
-                        byte(b'&', ERecord::Ampersand),
-                        two_bytes(b'<', b'-', ERecord::Arrow),
+    skip_second(
+        // Each field must start further right than the line with the `{`,
+        // so an outdented field ends the record instead of joining it.
+        indented_seq_skip_first(
+            byte(b'{', ERecord::Open),
+            record!(RecordHelp {
+                // You can optionally have an identifier followed by an '&' to
+                // make this a record update, e.g. { Foo.user & username: "blah" }.
+                prefix: reset_min_indent(optional(and(
+                    // We wrap the ident in an Expr here,
+                    // so that we have a Spaceable value to work with,
+                    // and then in canonicalization verify that it's an Expr::Var
+                    // (and not e.g. an `Expr::Access`) and extract its string.
+                    backtrackable(spaces_around(loc(record_prefix_identifier()))),
+                    record_prefix_operator(),
+                ))),
+                fields: collection_inner(
+                    skip_first(
+                        check_indent(ERecord::End),
+                        reset_min_indent(loc(record_field()))
                     ),
-                    |_arena, output| match output {
-                        Either::First(()) => RecordHelpPrefix::Update,
-                        Either::Second(()) => RecordHelpPrefix::Mapper,
-                    }
-                )
-            ))),
-            fields: collection_inner(
-                loc(record_field()),
-                byte(b',', ERecord::End),
-                RecordField::SpaceBefore
-            ),
-        })),
-        byte(b'}', ERecord::End),
+                    byte(b',', ERecord::End),
+                    RecordField::SpaceBefore
+                ),
+            }),
+        ),
+        record_end(),
     )
 }
 
+/// The `}` of a record. A field name that looks like an expression, e.g. `{ (x): 1 }` or
+/// `{ "x": 1 }`, ends up here, since no field could be parsed from it.
+fn record_end<'a>() -> impl Parser<'a, (), ERecord<'a>> {
+    move |arena: &'a Bump, state: State<'a>, min_indent: u32| match state.bytes().first() {
+        Some(b'(' | b'"') => Err((MadeProgress, ERecord::NonIdentifierField(state.pos()))),
+        _ => collection_end(b'}', ERecord::End, ERecord::UnexpectedComma)
+            .parse(arena, state, min_indent),
+    }
+}
+
 fn record_literal_help<'a>() -> impl Parser<'a, Expr<'a>, EExpr<'a>> {
     then(
         and(
@@ -3702,6 +4413,92 @@ fn string_like_literal_help<'a>() -> impl Parser<'a, Expr<'a>, EString<'a>> {
     )
 }
 
+/// A string literal at the start of an expression, along with any single-line strings that
+/// follow it on their own continuation lines, indented further than the first string's line.
+/// These are concatenated, e.g. `"part one "` followed by `"part two"` on the next line.
+///
+/// Strings on the same line, or with a comment between them, aren't concatenated, since the
+/// formatter couldn't keep that comment. In a list, the next line starts the next element
+/// instead. Strings that are arguments of a function call aren't concatenated either, so
+/// `Str.concat "a" "b"` still passes two.
+fn concatenated_str_literal_help<'a>(
+    options: ExprParseOptions,
+) -> impl Parser<'a, Expr<'a>, EString<'a>> {
+    (move |arena: &'a Bump, state: State<'a>, min_indent: u32| {
+        let line_indent = state.line_indent();
+
+        let (progress, mut lit, mut state) =
+            match string_like_literal_help().parse(arena, state, min_indent)? {
+                (progress, Expr::Str(lit), state) => (progress, lit, state),
+                other => return Ok(other),
+            };
+
+        while !matches!(lit, StrLiteral::Block(_)) {
+            let (spaces, next_state) =
+                match space0_e(EString::Open).parse(arena, state.clone(), min_indent) {
+                    Ok((_, spaces, next_state)) => (spaces, next_state),
+                    Err(_) => break,
+                };
+
+            let on_continuation_line = options.concat_strs_across_lines
+                && next_state.line_start != state.line_start
+                && next_state.column() > line_indent;
+            let only_newlines = spaces
+                .iter()
+                .all(|space| matches!(space, CommentOrNewline::Newline));
+
+            if !on_continuation_line || !only_newlines {
+                break;
+            }
+
+            let bytes = next_state.bytes();
+            if !bytes.starts_with(b"\"") || bytes.starts_with(b"\"\"\"") {
+                break;
+            }
+
+            let (_, next, next_state) = string_literal::parse_str_literal()
+                .parse(arena, next_state, min_indent)
+                .map_err(|(_, fail)| (MadeProgress, fail))?;
+
+            lit = concat_str_lines(arena, lit, next);
+            state = next_state;
+        }
+
+        Ok((progress, Expr::Str(lit), state))
+    })
+    .trace("concatenated_str_literal")
+}
+
+/// Join two single-line string literals into one
+fn concat_str_lines<'a>(
+    arena: &'a Bump,
+    first: StrLiteral<'a>,
+    second: StrLiteral<'a>,
+) -> StrLiteral<'a> {
+    if let (StrLiteral::PlainLine(a), StrLiteral::PlainLine(b)) = (first, second) {
+        let mut joined = bumpalo::collections::String::with_capacity_in(a.len() + b.len(), arena);
+        joined.push_str(a);
+        joined.push_str(b);
+
+        return StrLiteral::PlainLine(joined.into_bump_str());
+    }
+
+    let mut segments = Vec::new_in(arena);
+
+    for lit in [first, second] {
+        match lit {
+            StrLiteral::PlainLine("") => {}
+            StrLiteral::PlainLine(text) => segments.push(StrSegment::Plaintext(text)),
+            StrLiteral::Line(line) => segments.extend_from_slice(line),
+            StrLiteral::Block(_) => {
+                internal_error!("Only single-line strings are concatenated")
+            }
+        }
+    }
+
+    StrLiteral::Line(segments.into_bump_slice())
+}
+
 fn positive_number_literal_help<'a>() -> impl Parser<'a, Expr<'a>, ENumber> {
     map(
         crate::number_literal::positive_number_literal(),
@@ -3793,6 +4590,14 @@ fn bin_op<'a>(check_for_defs: bool) -> impl Parser<'a, BinOp, EExpr<'a>> {
     }
 }
 
+/// The precedence and associativity of a binary operator.
+///
+/// Operators with a higher precedence bind more tightly, e.g. `*` over `+`.
+/// Chains of non-associative operators, like `a == b == c`, need parentheses.
+pub fn binop_precedence(op: BinOp) -> (u8, Associativity) {
+    (op.precedence(), op.associativity())
+}
+
 fn operator<'a>() -> impl Parser<'a, OperatorOrDef, EExpr<'a>> {
     (move |_, state, _m| operator_help(EExpr::Start, EExpr::BadOperator, state)).trace("operator")
 }
@@ -1,8 +1,8 @@
 use std::fmt::Debug;
 
 use crate::ast::{
-    Collection, CommentOrNewline, Defs, Header, Malformed, Pattern, Spaced, Spaces, SpacesBefore,
-    StrLiteral, TypeAnnotation,
+    Collection, CommentOrNewline, Defs, Header, ImportAsKeyword, ImportExposedName, Malformed,
+    Module, Pattern, Spaced, Spaces, SpacesBefore, StrLiteral, TypeAnnotation,
 };
 use crate::blankspace::{space0_before_e, space0_e};
 use crate::expr::merge_spaces;
@@ -10,10 +10,10 @@ use crate::ident::{self, lowercase_ident, unqualified_ident, UppercaseIdent};
 use crate::parser::Progress::{self, *};
 use crate::parser::{
     and, backtrackable, byte, collection_trailing_sep_e, increment_min_indent, loc, map,
-    map_with_arena, optional, reset_min_indent, skip_first, skip_second, specialize_err, succeed,
-    then, two_bytes, zero_or_more, EExposes, EHeader, EImports, EPackageEntry, EPackageName,
-    EPackages, EParams, EProvides, ERequires, ETypedIdent, Parser, SourceError, SpaceProblem,
-    SyntaxError,
+    map_with_arena, optional, optional_with_default, reset_min_indent, skip_first, skip_second,
+    specialize_err, succeed, then, two_bytes, zero_or_more, EExposes, EHeader, EImports,
+    EPackageEntry, EPackageName, EPackages, EParams, EProvides, ERequires, ETypedIdent, Parser,
+    SourceError, SpaceProblem, SyntaxError,
 };
 use crate::pattern::record_pattern_fields;
 use crate::state::State;
@@ -47,6 +47,37 @@ pub fn parse_module_defs<'a>(
     }
 }
 
+/// Parse a whole module: an optional header followed by its top-level defs.
+///
+/// Source that doesn't start with a header keyword is parsed as defs only.
+pub fn parse_module<'a>(
+    arena: &'a bumpalo::Bump,
+    src: &'a str,
+) -> Result<Module<'a>, SyntaxError<'a>> {
+    let state = State::new(src.as_bytes());
+    let min_indent = 0;
+    match header().parse(arena, state.clone(), min_indent) {
+        Ok((_, header, state)) => {
+            let (item, defs) = header.item.upgrade_header_imports(arena);
+            let defs = parse_module_defs(arena, state, defs)?;
+
+            Ok(Module {
+                header: Some(SpacesBefore {
+                    before: header.before,
+                    item,
+                }),
+                defs,
+            })
+        }
+        Err((_, EHeader::Start(_))) => {
+            let defs = parse_module_defs(arena, state, Defs::default())?;
+
+            Ok(Module { header: None, defs })
+        }
+        Err((_, fail)) => Err(SyntaxError::Header(fail)),
+    }
+}
+
 pub fn parse_header<'a>(
     arena: &'a bumpalo::Bump,
     state: State<'a>,
@@ -447,15 +478,68 @@ fn platform_header<'a>() -> impl Parser<'a, PlatformHeader<'a>, EHeader<'a>> {
     record!(PlatformHeader {
         before_name: space0_e(EHeader::IndentStart),
         name: loc(specialize_err(EHeader::PlatformName, package_name())),
-        requires: specialize_err(EHeader::Requires, requires()),
-        exposes: specialize_err(EHeader::Exposes, exposes_modules()),
-        packages: specialize_err(EHeader::Packages, packages()),
-        imports: specialize_err(EHeader::Imports, imports()),
-        provides: specialize_err(EHeader::Provides, provides_exposed()),
+        requires: platform_section(
+            RequiresKeyword::KEYWORD,
+            specialize_err(EHeader::Requires, requires())
+        ),
+        exposes: platform_section(
+            ExposesKeyword::KEYWORD,
+            specialize_err(EHeader::Exposes, exposes_modules())
+        ),
+        packages: platform_section(
+            PackagesKeyword::KEYWORD,
+            specialize_err(EHeader::Packages, packages())
+        ),
+        imports: platform_section(
+            ImportsKeyword::KEYWORD,
+            specialize_err(EHeader::Imports, imports())
+        ),
+        provides: platform_section(
+            ProvidesKeyword::KEYWORD,
+            specialize_err(EHeader::Provides, provides_exposed())
+        ),
     })
     .trace("platform_header")
 }
 
+/// The sections of a platform header, in the order they must be written.
+const PLATFORM_SECTIONS: [&str; 5] = [
+    RequiresKeyword::KEYWORD,
+    ExposesKeyword::KEYWORD,
+    PackagesKeyword::KEYWORD,
+    ImportsKeyword::KEYWORD,
+    ProvidesKeyword::KEYWORD,
+];
+
+/// Parse the platform header section starting with `expected`, but first check
+/// whether a different section is there instead, because it's out of order or
+/// the expected section is missing.
+fn platform_section<'a, T>(
+    expected: &'static str,
+    section: impl Parser<'a, T, EHeader<'a>>,
+) -> impl Parser<'a, T, EHeader<'a>> {
+    use crate::parser::keyword;
+
+    move |arena: &'a bumpalo::Bump, state: State<'a>, min_indent: u32| {
+        if let Ok((_, _, peek)) =
+            space0_e(EHeader::IndentStart).parse(arena, state.clone(), min_indent)
+        {
+            let start = peek.pos();
+
+            for found in PLATFORM_SECTIONS.into_iter().filter(|kw| *kw != expected) {
+                if let Ok((_, (), after)) =
+                    keyword(found, EHeader::Start).parse(arena, peek.clone(), min_indent)
+                {
+                    let region = Region::new(start, after.pos());
+                    return Err((MadeProgress, EHeader::UnexpectedSection(expected, region)));
+                }
+            }
+        }
+
+        section.parse(arena, state, min_indent)
+    }
+}
+
 fn provides_to_package<'a>() -> impl Parser<'a, To<'a>, EProvides<'a>> {
     one_of![
         specialize_err(
@@ -569,6 +653,62 @@ where
     ))
 }
 
+/// e.g. `map` or `map as listMap` in an import's exposing list
+///
+/// An alias must be capitalized like the name it renames, since values can
+/// only be renamed to values, and types to types.
+pub(crate) fn import_exposed_entry<'a, E>(
+    name_problem: fn(Position) -> E,
+    as_problem: fn(Position) -> E,
+    indent_as_problem: fn(Position) -> E,
+    indent_alias_problem: fn(Position) -> E,
+    alias_case_problem: fn(Region) -> E,
+) -> impl Parser<'a, Loc<Spaced<'a, ImportExposedName<'a>>>, E>
+where
+    E: 'a + SpaceProblem,
+{
+    loc(then(
+        and(
+            specialize_err(move |_, pos| name_problem(pos), unqualified_ident()),
+            optional(map(
+                and(
+                    spaces_around_keyword(
+                        ImportAsKeyword,
+                        as_problem,
+                        indent_as_problem,
+                        indent_alias_problem,
+                    ),
+                    loc(map(
+                        specialize_err(
+                            move |_, pos| indent_alias_problem(pos),
+                            unqualified_ident(),
+                        ),
+                        ExposedName::new,
+                    )),
+                ),
+                |(keyword, item)| KeywordItem { keyword, item },
+            )),
+        ),
+        move |_arena, state, progress, (name, alias)| {
+            let is_type = |name: &str| name.starts_with(|c: char| c.is_uppercase());
+
+            match alias {
+                Some(alias) if is_type(name) != is_type(alias.item.value.as_str()) => {
+                    Err((MadeProgress, alias_case_problem(alias.item.region)))
+                }
+                _ => Ok((
+                    progress,
+                    Spaced::Item(ImportExposedName {
+                        name: ExposedName::new(name),
+                        alias,
+                    }),
+                    state,
+                )),
+            }
+        },
+    ))
+}
+
 #[inline(always)]
 fn requires<'a>(
 ) -> impl Parser<'a, KeywordItem<'a, RequiresKeyword, PlatformRequires<'a>>, ERequires<'a>> {
@@ -832,12 +972,10 @@ where
 fn imports_entry<'a>() -> impl Parser<'a, Spaced<'a, ImportsEntry<'a>>, EImports> {
     type Temp<'a> = (
         (Option<&'a str>, ModuleName<'a>),
-        Option<Collection<'a, Loc<Spaced<'a, ExposedName<'a>>>>>,
+        Collection<'a, Loc<Spaced<'a, ImportExposedName<'a>>>>,
     );
 
-    let spaced_import = |((opt_shortname, module_name), opt_values): Temp<'a>| {
-        let exposed_values = opt_values.unwrap_or_else(Collection::empty);
-
+    let spaced_import = |((opt_shortname, module_name), exposed_values): Temp<'a>| {
         let entry = match opt_shortname {
             Some(shortname) => ImportsEntry::Package(shortname, module_name, exposed_values),
 
@@ -860,16 +998,25 @@ fn imports_entry<'a>() -> impl Parser<'a, Spaced<'a, ImportsEntry<'a>>, EImports
                     module_name_help(EImports::ModuleName)
                 ),
                 // e.g. `.{ Task, after}`
-                optional(skip_first(
-                    byte(b'.', EImports::ExposingDot),
-                    collection_trailing_sep_e(
-                        byte(b'{', EImports::SetStart),
-                        exposes_entry(EImports::Identifier),
-                        byte(b',', EImports::SetEnd),
-                        byte(b'}', EImports::SetEnd),
-                        Spaced::SpaceBefore
-                    )
-                ))
+                optional_with_default(
+                    skip_first(
+                        byte(b'.', EImports::ExposingDot),
+                        collection_trailing_sep_e(
+                            byte(b'{', EImports::SetStart),
+                            import_exposed_entry(
+                                EImports::Identifier,
+                                EImports::AsKeyword,
+                                EImports::AsKeyword,
+                                EImports::Identifier,
+                                EImports::ExposedAliasCase,
+                            ),
+                            byte(b',', EImports::SetEnd),
+                            byte(b'}', EImports::SetEnd),
+                            Spaced::SpaceBefore
+                        )
+                    ),
+                    Collection::empty()
+                )
             ),
             spaced_import
         )
@@ -1258,14 +1405,14 @@ pub enum ImportsEntry<'a> {
     /// e.g. `Hello` or `Hello exposing [hello]` see roc-lang.org/examples/MultipleRocFiles/README.html  
     Module(
         ModuleName<'a>,
-        Collection<'a, Loc<Spaced<'a, ExposedName<'a>>>>,
+        Collection<'a, Loc<Spaced<'a, ImportExposedName<'a>>>>,
     ),
 
     /// e.g. `pf.Stdout` or `pf.Stdout exposing [line]`
     Package(
         &'a str,
         ModuleName<'a>,
-        Collection<'a, Loc<Spaced<'a, ExposedName<'a>>>>,
+        Collection<'a, Loc<Spaced<'a, ImportExposedName<'a>>>>,
     ),
 
     /// e.g "path/to/my/file.txt" as myFile : Str
@@ -240,6 +240,8 @@ pub enum BadIdent {
     QualifiedTag(Position),
     WeirdAccessor(Position),
     WeirdDotAccess(Position),
+    /// A `.` with no field name or tuple index after it, as in `foo.`
+    TrailingDot(Position),
     WeirdDotQualified(Position),
     StrayDot(Position),
     StrayAmpersand(Position),
@@ -527,6 +529,10 @@ fn chomp_identifier_chain<'a>(
                 chomped as u32 + 1,
                 BadIdent::WeirdDotQualified(pos.bump_column(chomped as u32 + 1)),
             )),
+            Err(width) if !starts_accessor_name(&buffer[chomped + width as usize..]) => Err((
+                chomped as u32 + width,
+                BadIdent::TrailingDot(pos.bump_column(chomped as u32 + width - 1)),
+            )),
             Err(width) => Err((
                 chomped as u32 + width,
                 BadIdent::WeirdDotAccess(pos.bump_column(chomped as u32 + width)),
@@ -625,6 +631,14 @@ fn chomp_concrete_type(buffer: &[u8]) -> Result<(&str, &str, usize), Progress> {
     }
 }
 
+/// Whether the bytes after an access `.` could plausibly be (a malformed attempt at) a field name
+fn starts_accessor_name(buffer: &[u8]) -> bool {
+    match buffer.first() {
+        None => false,
+        Some(b) => !(b.is_ascii_whitespace() || (b.is_ascii_punctuation() && *b != b'_')),
+    }
+}
+
 fn chomp_access_chain<'a>(buffer: &'a [u8], parts: &mut Vec<'a, Accessor<'a>>) -> Result<u32, u32> {
     let mut chomped = 0;
 
@@ -21,6 +21,12 @@ pub const WHERE: &str = "where";
 // These keywords are valid in headers
 pub const PLATFORM: &str = "platform";
 
+/// The reserved words, which can never be used as identifiers. For example,
+/// `f then` is an error rather than `f` applied to a variable named `then`.
+///
+/// Identifiers that merely start with one of these, like `isEmpty` or `asList`,
+/// are fine. The type, import and header keywords above are only reserved in
+/// those positions.
 pub const KEYWORDS: [&str; 11] = [
     IF, THEN, ELSE, WHEN, AS, IS, DBG, IMPORT, EXPECT, EXPECT_FX, CRASH,
 ];
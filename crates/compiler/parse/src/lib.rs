@@ -8,6 +8,7 @@
 pub mod parser;
 pub mod ast;
 pub mod blankspace;
+pub mod doc_comments;
 pub mod expr;
 pub mod header;
 pub mod highlight;
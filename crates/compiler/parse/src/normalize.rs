@@ -5,12 +5,12 @@ use roc_region::all::{Loc, Position, Region};
 
 use crate::{
     ast::{
-        AbilityImpls, AbilityMember, AssignedField, Collection, Defs, Expr, FullAst, Header,
-        Implements, ImplementsAbilities, ImplementsAbility, ImplementsClause, ImportAlias,
-        ImportAsKeyword, ImportExposingKeyword, ImportedModuleName, IngestedFileAnnotation,
-        IngestedFileImport, ModuleImport, ModuleImportParams, Pattern, PatternAs, Spaced, Spaces,
-        SpacesBefore, StrLiteral, StrSegment, Tag, TypeAnnotation, TypeDef, TypeHeader, ValueDef,
-        WhenBranch,
+        AbilityImpls, AbilityMember, AssignedField, BackpassingPatterns, Collection, Defs, Expr,
+        FullAst, Header, Implements, ImplementsAbilities, ImplementsAbility, ImplementsClause,
+        ImportAlias, ImportAsKeyword, ImportExposedName, ImportExposingKeyword, ImportedModuleName,
+        IngestedFileAnnotation, IngestedFileImport, ModuleImport, ModuleImportParams, Pattern,
+        PatternAs, Spaced, Spaces, SpacesBefore, StrLiteral, StrSegment, Tag, TypeAnnotation,
+        TypeDef, TypeHeader, ValueDef, WhenBranch,
     },
     header::{
         AppHeader, ExposedName, ExposesKeyword, HostedHeader, ImportsEntry, ImportsKeyword,
@@ -490,6 +490,15 @@ impl<'a> Normalize<'a> for ImportAlias<'a> {
     }
 }
 
+impl<'a> Normalize<'a> for ImportExposedName<'a> {
+    fn normalize(&self, arena: &'a Bump) -> Self {
+        ImportExposedName {
+            name: self.name,
+            alias: self.alias.normalize(arena),
+        }
+    }
+}
+
 impl<'a> Normalize<'a> for ImportAsKeyword {
     fn normalize(&self, _arena: &'a Bump) -> Self {
         *self
@@ -536,6 +545,15 @@ impl<'a> Normalize<'a> for WhenBranch<'a> {
     }
 }
 
+impl<'a> Normalize<'a> for BackpassingPatterns<'a> {
+    fn normalize(&self, arena: &'a Bump) -> Self {
+        BackpassingPatterns {
+            patterns: self.patterns.normalize(arena),
+            ann: self.ann.normalize(arena),
+        }
+    }
+}
+
 impl<'a, T: Normalize<'a> + Copy + std::fmt::Debug> Normalize<'a> for AssignedField<'a, T> {
     fn normalize(&self, arena: &'a Bump) -> Self {
         match *self {
@@ -711,6 +729,7 @@ impl<'a> Normalize<'a> for Expr<'a> {
                 fields: fields.normalize(arena),
             },
             Expr::Tuple(a) => Expr::Tuple(a.normalize(arena)),
+            Expr::Spread(a) => Expr::Spread(arena.alloc(a.normalize(arena))),
             Expr::Var { module_name, ident } => Expr::Var { module_name, ident },
             Expr::Underscore(a) => Expr::Underscore(a),
             Expr::Tag(a) => Expr::Tag(a),
@@ -814,6 +833,7 @@ fn remove_spaces_bad_ident(ident: BadIdent) -> BadIdent {
         BadIdent::QualifiedTag(_) => BadIdent::QualifiedTag(Position::zero()),
         BadIdent::WeirdAccessor(_) => BadIdent::WeirdAccessor(Position::zero()),
         BadIdent::WeirdDotAccess(_) => BadIdent::WeirdDotAccess(Position::zero()),
+        BadIdent::TrailingDot(_) => BadIdent::TrailingDot(Position::zero()),
         BadIdent::WeirdDotQualified(_) => BadIdent::WeirdDotQualified(Position::zero()),
         BadIdent::StrayDot(_) => BadIdent::StrayDot(Position::zero()),
         BadIdent::StrayAmpersand(_) => BadIdent::StrayAmpersand(Position::zero()),
@@ -876,9 +896,10 @@ impl<'a> Normalize<'a> for Pattern<'a> {
 impl<'a> Normalize<'a> for TypeAnnotation<'a> {
     fn normalize(&self, arena: &'a Bump) -> Self {
         match *self {
-            TypeAnnotation::Function(a, b) => TypeAnnotation::Function(
+            TypeAnnotation::Function(a, b, c) => TypeAnnotation::Function(
                 arena.alloc(a.normalize(arena)),
                 arena.alloc(b.normalize(arena)),
+                c.map(|c| &*arena.alloc(c.normalize(arena))),
             ),
             TypeAnnotation::Apply(a, b, c) => TypeAnnotation::Apply(a, b, c.normalize(arena)),
             TypeAnnotation::BoundVariable(a) => TypeAnnotation::BoundVariable(a),
@@ -993,11 +1014,20 @@ impl<'a> Normalize<'a> for EExpr<'a> {
             EExpr::Start(_pos) => EExpr::Start(Position::zero()),
             EExpr::End(_pos) => EExpr::End(Position::zero()),
             EExpr::BadExprEnd(_pos) => EExpr::BadExprEnd(Position::zero()),
+            EExpr::UnparenthesizedKeywordArg(keyword, _pos) => {
+                EExpr::UnparenthesizedKeywordArg(keyword, Position::zero())
+            }
             EExpr::Space(inner_err, _pos) => EExpr::Space(*inner_err, Position::zero()),
             EExpr::Dot(_pos) => EExpr::Dot(Position::zero()),
             EExpr::Access(_pos) => EExpr::Access(Position::zero()),
             EExpr::UnaryNot(_pos) => EExpr::UnaryNot(Position::zero()),
             EExpr::UnaryNegate(_pos) => EExpr::UnaryNegate(Position::zero()),
+            EExpr::UnaryPlus(_region) => EExpr::UnaryPlus(Region::zero()),
+            EExpr::MissingLeftOperand(op, _region) => {
+                EExpr::MissingLeftOperand(*op, Region::zero())
+            }
+            EExpr::RecordNotAPattern(_region) => EExpr::RecordNotAPattern(Region::zero()),
+            EExpr::BadSpread(kind, _region) => EExpr::BadSpread(*kind, Region::zero()),
             EExpr::BadOperator(inner_err, _pos) => {
                 EExpr::BadOperator(arena.alloc(inner_err.normalize(arena)), Position::zero())
             }
@@ -1018,6 +1048,7 @@ impl<'a> Normalize<'a> for EExpr<'a> {
             EExpr::IndentDefBody(_pos) => EExpr::IndentDefBody(Position::zero()),
             EExpr::IndentEquals(_pos) => EExpr::IndentEquals(Position::zero()),
             EExpr::IndentAnnotation(_pos) => EExpr::IndentAnnotation(Position::zero()),
+            EExpr::IndentOperator(_pos) => EExpr::IndentOperator(Position::zero()),
             EExpr::Equals(_pos) => EExpr::Equals(Position::zero()),
             EExpr::Colon(_pos) => EExpr::Colon(Position::zero()),
             EExpr::DoubleColon(_pos) => EExpr::DoubleColon(Position::zero()),
@@ -1062,6 +1093,7 @@ impl<'a> Normalize<'a> for EExpr<'a> {
             EExpr::IndentEnd(_pos) => EExpr::IndentEnd(Position::zero()),
             EExpr::UnexpectedComma(_pos) => EExpr::UnexpectedComma(Position::zero()),
             EExpr::UnexpectedTopLevelExpr(_pos) => EExpr::UnexpectedTopLevelExpr(Position::zero()),
+            EExpr::DoubleEqualsDef(_region) => EExpr::DoubleEqualsDef(Region::zero()),
             EExpr::StmtAfterExpr(_pos) => EExpr::StmtAfterExpr(Position::zero()),
             EExpr::RecordUpdateOldBuilderField(_pos) => {
                 EExpr::RecordUpdateOldBuilderField(Region::zero())
@@ -1081,6 +1113,9 @@ impl<'a> Normalize<'a> for EList<'a> {
         match self {
             EList::Open(_pos) => EList::Open(Position::zero()),
             EList::End(_pos) => EList::End(Position::zero()),
+            EList::UnexpectedComma(_region) => EList::UnexpectedComma(Region::zero()),
+            EList::MixedSeparators(_pos) => EList::MixedSeparators(Position::zero()),
+            EList::MisalignedElement(_pos) => EList::MisalignedElement(Position::zero()),
             EList::Space(inner_err, _pos) => EList::Space(*inner_err, Position::zero()),
             EList::Expr(inner_err, _pos) => {
                 EList::Expr(arena.alloc(inner_err.normalize(arena)), Position::zero())
@@ -1103,6 +1138,9 @@ impl<'a> Normalize<'a> for EString<'a> {
             EString::EndlessMultiLine(_) => EString::EndlessMultiLine(Position::zero()),
             EString::EndlessSingleQuote(_) => EString::EndlessSingleQuote(Position::zero()),
             EString::UnknownEscape(_) => EString::UnknownEscape(Position::zero()),
+            EString::ByteEscapeDigits(_) => EString::ByteEscapeDigits(Region::zero()),
+            EString::NonAsciiByteEscape(_) => EString::NonAsciiByteEscape(Region::zero()),
+            EString::BadEscapes(bad_escapes) => EString::BadEscapes(bad_escapes.normalize(arena)),
             EString::Format(inner, _) => {
                 EString::Format(arena.alloc(inner.normalize(arena)), Position::zero())
             }
@@ -1134,6 +1172,7 @@ impl<'a> Normalize<'a> for EClosure<'a> {
             EClosure::IndentArrow(_) => EClosure::IndentArrow(Position::zero()),
             EClosure::IndentBody(_) => EClosure::IndentBody(Position::zero()),
             EClosure::IndentArg(_) => EClosure::IndentArg(Position::zero()),
+            EClosure::OrPattern(_) => EClosure::OrPattern(Position::zero()),
         }
     }
 }
@@ -1156,13 +1195,16 @@ impl<'a> Normalize<'a> for ERecord<'a> {
     fn normalize(&self, arena: &'a Bump) -> Self {
         match self {
             ERecord::End(_) => ERecord::End(Position::zero()),
+            ERecord::UnexpectedComma(_) => ERecord::UnexpectedComma(Region::zero()),
             ERecord::Open(_) => ERecord::Open(Position::zero()),
             ERecord::Field(_pos) => ERecord::Field(Position::zero()),
+            ERecord::NonIdentifierField(_) => ERecord::NonIdentifierField(Position::zero()),
             ERecord::UnderscoreField(_pos) => ERecord::Field(Position::zero()),
             ERecord::Colon(_) => ERecord::Colon(Position::zero()),
             ERecord::QuestionMark(_) => ERecord::QuestionMark(Position::zero()),
             ERecord::Arrow(_) => ERecord::Arrow(Position::zero()),
             ERecord::Ampersand(_) => ERecord::Ampersand(Position::zero()),
+            ERecord::DoubleAmpersand(_) => ERecord::DoubleAmpersand(Region::zero()),
             ERecord::Expr(inner_err, _) => {
                 ERecord::Expr(arena.alloc(inner_err.normalize(arena)), Position::zero())
             }
@@ -1223,6 +1265,7 @@ impl<'a> Normalize<'a> for EImport<'a> {
             EImport::Exposing(_) => EImport::Exposing(Position::zero()),
             EImport::ExposingListStart(_) => EImport::ExposingListStart(Position::zero()),
             EImport::ExposedName(_) => EImport::ExposedName(Position::zero()),
+            EImport::ExposedAliasCase(_) => EImport::ExposedAliasCase(Region::zero()),
             EImport::ExposingListEnd(_) => EImport::ExposingListEnd(Position::zero()),
             EImport::IndentIngestedPath(_) => EImport::IndentIngestedPath(Position::zero()),
             EImport::IngestedPath(_) => EImport::IngestedPath(Position::zero()),
@@ -1266,6 +1309,7 @@ impl<'a> Normalize<'a> for EType<'a> {
             EType::TStart(_) => EType::TStart(Position::zero()),
             EType::TEnd(_) => EType::TEnd(Position::zero()),
             EType::TFunctionArgument(_) => EType::TFunctionArgument(Position::zero()),
+            EType::TFunctionEffect(_) => EType::TFunctionEffect(Position::zero()),
             EType::TWhereBar(_) => EType::TWhereBar(Position::zero()),
             EType::TImplementsClause(_) => EType::TImplementsClause(Position::zero()),
             EType::TAbilityImpl(inner_err, _) => {
@@ -1393,6 +1437,8 @@ impl<'a> Normalize<'a> for ETypeTagUnion<'a> {
         match self {
             ETypeTagUnion::End(_) => ETypeTagUnion::End(Position::zero()),
             ETypeTagUnion::Open(_) => ETypeTagUnion::Open(Position::zero()),
+            ETypeTagUnion::PipeWithoutTag(_) => ETypeTagUnion::PipeWithoutTag(Position::zero()),
+            ETypeTagUnion::MixedSeparators(_) => ETypeTagUnion::MixedSeparators(Position::zero()),
             ETypeTagUnion::Type(inner_err, _) => {
                 ETypeTagUnion::Type(arena.alloc(inner_err.normalize(arena)), Position::zero())
             }
@@ -1509,6 +1555,7 @@ impl<'a> Normalize<'a> for EWhen<'a> {
             }
             EWhen::Arrow(_) => EWhen::Arrow(Position::zero()),
             EWhen::Bar(_) => EWhen::Bar(Position::zero()),
+            EWhen::DoubleBar(_) => EWhen::DoubleBar(Region::zero()),
             EWhen::IfToken(_) => EWhen::IfToken(Position::zero()),
             EWhen::IfGuard(inner_err, _) => {
                 EWhen::IfGuard(arena.alloc(inner_err.normalize(arena)), Position::zero())
@@ -1520,11 +1567,13 @@ impl<'a> Normalize<'a> for EWhen<'a> {
                 EWhen::Branch(arena.alloc(inner_err.normalize(arena)), Position::zero())
             }
             EWhen::IndentCondition(_) => EWhen::IndentCondition(Position::zero()),
+            EWhen::IndentIs(_) => EWhen::IndentIs(Position::zero()),
             EWhen::IndentPattern(_) => EWhen::IndentPattern(Position::zero()),
             EWhen::IndentArrow(_) => EWhen::IndentArrow(Position::zero()),
             EWhen::IndentBranch(_) => EWhen::IndentBranch(Position::zero()),
             EWhen::IndentIfGuard(_) => EWhen::IndentIfGuard(Position::zero()),
             EWhen::PatternAlignment(_alignment, _) => EWhen::PatternAlignment(0, Position::zero()),
+            EWhen::NoBranches(_) => EWhen::NoBranches(Region::zero()),
         }
     }
 }
@@ -1598,6 +1647,9 @@ impl<'a> Normalize<'a> for EHeader<'a> {
             }
             EHeader::IndentStart(_) => EHeader::IndentStart(Position::zero()),
             EHeader::InconsistentModuleName(_) => EHeader::InconsistentModuleName(Region::zero()),
+            EHeader::UnexpectedSection(expected, _) => {
+                EHeader::UnexpectedSection(expected, Region::zero())
+            }
         }
     }
 }
@@ -1727,6 +1779,7 @@ impl<'a> Normalize<'a> for EImports {
             EImports::SetEnd(_) => EImports::SetEnd(Position::zero()),
             EImports::TypedIdent(_) => EImports::TypedIdent(Position::zero()),
             EImports::AsKeyword(_) => EImports::AsKeyword(Position::zero()),
+            EImports::ExposedAliasCase(_) => EImports::ExposedAliasCase(Region::zero()),
             EImports::StrLiteral(_) => EImports::StrLiteral(Position::zero()),
         }
     }
@@ -1,6 +1,7 @@
 use crate::ast::Base;
 use crate::parser::{ENumber, ParseResult, Parser, Progress};
 use crate::state::State;
+use roc_region::all::Region;
 
 pub enum NumLiteral<'a> {
     Float(&'a str),
@@ -44,6 +45,44 @@ pub fn number_literal<'a>() -> impl Parser<'a, NumLiteral<'a>, ENumber> {
     }
 }
 
+/// Like [number_literal], but rejects underscores that don't separate digits,
+/// e.g. the trailing one in `1_`, the first of the two in `1__2`, or the one
+/// before the suffix in `1_u8`.
+///
+/// The compiler accepts these; this is for tools that enforce clean formatting.
+pub fn number_literal_strict<'a>() -> impl Parser<'a, NumLiteral<'a>, ENumber> {
+    move |arena, state: State<'a>, min_indent: u32| {
+        let (progress, literal, new) = number_literal().parse(arena, state.clone(), min_indent)?;
+
+        let width = state.bytes().len() - new.bytes().len();
+
+        let is_digit = match literal {
+            NumLiteral::NonBase10Int {
+                base: Base::Hex, ..
+            } => u8::is_ascii_hexdigit,
+            _ => u8::is_ascii_digit,
+        };
+
+        if let Some(index) = first_misplaced_underscore(&state.bytes()[..width], is_digit) {
+            let start = state.pos().bump_column(index as u32);
+            let region = Region::new(start, start.bump_column(1));
+
+            return Err((Progress::MadeProgress, ENumber::MisplacedUnderscore(region)));
+        }
+
+        Ok((progress, literal, new))
+    }
+}
+
+/// The index of the first underscore in `bytes` that isn't followed by a digit,
+/// e.g. the one in `1_u8`, where it's followed by a type suffix instead
+fn first_misplaced_underscore(bytes: &[u8], is_digit: fn(&u8) -> bool) -> Option<usize> {
+    bytes
+        .iter()
+        .enumerate()
+        .position(|(index, byte)| *byte == b'_' && !bytes.get(index + 1).is_some_and(is_digit))
+}
+
 fn parse_number_base<'a>(
     is_negated: bool,
     bytes: &'a [u8],
@@ -52,7 +91,7 @@ fn parse_number_base<'a>(
     match bytes.get(0..2) {
         Some(b"0b") => chomp_number_base(Base::Binary, is_negated, &bytes[2..], state),
         Some(b"0o") => chomp_number_base(Base::Octal, is_negated, &bytes[2..], state),
-        Some(b"0x") => chomp_number_base(Base::Hex, is_negated, &bytes[2..], state),
+        Some(b"0x") => chomp_number_hex(is_negated, &bytes[2..], state),
         _ => chomp_number_dec(is_negated, bytes, state),
     }
 }
@@ -67,6 +106,15 @@ fn chomp_number_base<'a>(
 
     let string = unsafe { std::str::from_utf8_unchecked(&bytes[..chomped]) };
 
+    if let Some(index) = first_invalid_digit(base, string) {
+        // e.g. the `2` in `0b12`
+        let offset = is_negative as usize + 2 + index;
+        let start = state.pos().bump_column(offset as u32);
+        let region = Region::new(start, start.bump_column(1));
+
+        return Err((Progress::MadeProgress, ENumber::InvalidDigit(base, region)));
+    }
+
     let new = state.advance(chomped + 2 + is_negative as usize);
 
     Ok((
@@ -80,6 +128,109 @@ fn chomp_number_base<'a>(
     ))
 }
 
+/// The width suffixes a number literal can end with, e.g. the `u8` in `0xFFu8`
+const WIDTH_SUFFIXES: [&str; 13] = [
+    "u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128", "dec", "f32", "f64",
+];
+
+/// The index of the first byte in `string` that is not a digit in the given base.
+/// Underscores and a width suffix are allowed.
+fn first_invalid_digit(base: Base, string: &str) -> Option<usize> {
+    let radix = match base {
+        Base::Hex => 16,
+        Base::Decimal => 10,
+        Base::Octal => 8,
+        Base::Binary => 2,
+    };
+
+    let digits = WIDTH_SUFFIXES
+        .iter()
+        .find_map(|suffix| string.strip_suffix(suffix))
+        .unwrap_or(string);
+
+    digits
+        .bytes()
+        .position(|byte| byte != b'_' && !(byte as char).is_digit(radix))
+}
+
+/// Hex literals are ints, unless they have a fraction or a binary exponent,
+/// like the C-style hex floats `0x1.8p3` and `0xAp-2`.
+fn chomp_number_hex<'a>(
+    is_negative: bool,
+    bytes: &'a [u8],
+    state: State<'a>,
+) -> ParseResult<'a, NumLiteral<'a>, ENumber> {
+    let mut chomped = chomp_hex_digits(bytes);
+
+    if bytes.get(chomped) == Some(&b'.') {
+        chomped += 1;
+        chomped += chomp_hex_digits(&bytes[chomped..]);
+
+        match chomp_binary_exponent(&bytes[chomped..]) {
+            Some(width) => chomped += width,
+            None => {
+                // e.g. `0x1.8`; the exponent is what makes this a float
+                let start = state.pos();
+                let new = state.advance(chomped + 2 + is_negative as usize);
+                let region = Region::new(start, new.pos());
+
+                return Err((
+                    Progress::MadeProgress,
+                    ENumber::HexFloatWithoutExponent(region),
+                ));
+            }
+        }
+    } else {
+        match chomp_binary_exponent(&bytes[chomped..]) {
+            Some(width) => chomped += width,
+            None => return chomp_number_base(Base::Hex, is_negative, bytes, state),
+        }
+    }
+
+    // a width suffix, e.g. the `f32` in `0x1p3f32`
+    chomped += bytes[chomped..]
+        .iter()
+        .take_while(|byte| byte.is_ascii_alphanumeric())
+        .count();
+
+    // keep the `0x` prefix (and any minus sign) in the float's string
+    let width = chomped + 2 + is_negative as usize;
+    let string = unsafe { std::str::from_utf8_unchecked(&state.bytes()[..width]) };
+
+    Ok((
+        Progress::MadeProgress,
+        NumLiteral::Float(string),
+        state.advance(width),
+    ))
+}
+
+fn chomp_hex_digits(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .take_while(|byte| byte.is_ascii_hexdigit() || **byte == b'_')
+        .count()
+}
+
+/// The `p3` or `P-2` at the end of a hex float, whose exponent is in decimal
+fn chomp_binary_exponent(bytes: &[u8]) -> Option<usize> {
+    let sign_width = match bytes {
+        [b'p' | b'P', b'+' | b'-', ..] => 1,
+        [b'p' | b'P', ..] => 0,
+        _ => return None,
+    };
+
+    let digits = bytes[1 + sign_width..]
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit() || **byte == b'_')
+        .count();
+
+    if digits == 0 {
+        None
+    } else {
+        Some(1 + sign_width + digits)
+    }
+}
+
 fn chomp_number_dec<'a>(
     is_negative: bool,
     bytes: &'a [u8],
@@ -100,8 +251,15 @@ fn chomp_number_dec<'a>(
     let string =
         unsafe { std::str::from_utf8_unchecked(&state.bytes()[0..chomped + is_negative as usize]) };
 
+    let start = state.pos();
     let new = state.advance(chomped + is_negative as usize);
 
+    if has_leading_zero(&bytes[..chomped]) {
+        // e.g. `007`; a lone `0`, `0.5`, and `0x0F` are all fine
+        let region = Region::new(start, new.pos());
+        return Err((Progress::MadeProgress, ENumber::LeadingZero(region)));
+    }
+
     Ok((
         Progress::MadeProgress,
         if is_float {
@@ -113,6 +271,17 @@ fn chomp_number_dec<'a>(
     ))
 }
 
+fn has_leading_zero(number: &[u8]) -> bool {
+    match number {
+        // underscores are only separators, so `0_7` has a leading zero just like `07`
+        [b'0', rest @ ..] => rest
+            .iter()
+            .find(|byte| **byte != b'_')
+            .is_some_and(u8::is_ascii_digit),
+        _ => false,
+    }
+}
+
 fn chomp_number(mut bytes: &[u8]) -> (bool, usize) {
     let start_bytes_len = bytes.len();
     let mut is_float = false;
@@ -1,6 +1,7 @@
 use crate::state::State;
 use bumpalo::collections::vec::Vec;
 use bumpalo::Bump;
+use roc_module::called_via::BinOp;
 use roc_region::all::{Loc, Position, Region};
 use Progress::*;
 
@@ -130,6 +131,8 @@ pub enum EHeader<'a> {
     IndentStart(Position),
 
     InconsistentModuleName(Region),
+    /// A platform header section where another was expected, e.g. `exposes` before `requires`
+    UnexpectedSection(&'static str, Region),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -244,6 +247,7 @@ pub enum EImports {
     SetEnd(Position),
     TypedIdent(Position),
     AsKeyword(Position),
+    ExposedAliasCase(Region),
     StrLiteral(Position),
 }
 
@@ -302,14 +306,26 @@ pub enum EExpr<'a> {
     Start(Position),
     End(Position),
     BadExprEnd(Position),
+    /// An `if` or `when` passed as a function argument without parentheses, e.g. `f if c then a else b`
+    UnparenthesizedKeywordArg(&'a str, Position),
     StmtAfterExpr(Position),
+    /// A def written with `==` instead of `=`, e.g. `x == 5` followed by more statements
+    DoubleEqualsDef(Region),
     Space(BadInputError, Position),
 
     Dot(Position),
     Access(Position),
     UnaryNot(Position),
     UnaryNegate(Position),
+    /// A number written with a leading `+`, e.g. `+5`
+    UnaryPlus(Region),
+    /// An expression starting with a binary operator, e.g. `* 2`
+    MissingLeftOperand(BinOp, Region),
     BadOperator(&'a str, Position),
+    /// A record before a `:` whose fields are not all patterns, e.g. `{ x: f y } : T`
+    RecordNotAPattern(Region),
+    /// A `..` spread that is malformed, or that is not an element of a list or a tuple
+    BadSpread(ESpread, Region),
 
     DefMissingFinalExpr(Position),
     DefMissingFinalExpr2(&'a EExpr<'a>, Position),
@@ -319,6 +335,7 @@ pub enum EExpr<'a> {
     IndentDefBody(Position),
     IndentEquals(Position),
     IndentAnnotation(Position),
+    IndentOperator(Position),
     Equals(Position),
     Colon(Position),
     DoubleColon(Position),
@@ -361,9 +378,99 @@ pub enum EExpr<'a> {
     UnexpectedTopLevelExpr(Position),
 }
 
+impl<'a> EExpr<'a> {
+    /// Where this error happened. For an error inside a nested construct, like a list or a
+    /// `when`, this is where that construct starts; the nested error has the exact position.
+    pub fn pos(&self) -> Position {
+        use EExpr::*;
+
+        match self {
+            DoubleEqualsDef(region)
+            | UnaryPlus(region)
+            | MissingLeftOperand(_, region)
+            | RecordNotAPattern(region)
+            | BadSpread(_, region)
+            | RecordUpdateOldBuilderField(region)
+            | RecordUpdateIgnoredField(region)
+            | RecordBuilderOldBuilderField(region)
+            | ElmStyleFunction(region, _) => region.start(),
+
+            TrailingOperator(pos)
+            | Start(pos)
+            | End(pos)
+            | BadExprEnd(pos)
+            | UnparenthesizedKeywordArg(_, pos)
+            | StmtAfterExpr(pos)
+            | Space(_, pos)
+            | Dot(pos)
+            | Access(pos)
+            | UnaryNot(pos)
+            | UnaryNegate(pos)
+            | BadOperator(_, pos)
+            | DefMissingFinalExpr(pos)
+            | DefMissingFinalExpr2(_, pos)
+            | Type(_, pos)
+            | Pattern(_, pos)
+            | Ability(_, pos)
+            | IndentDefBody(pos)
+            | IndentEquals(pos)
+            | IndentAnnotation(pos)
+            | IndentOperator(pos)
+            | Equals(pos)
+            | Colon(pos)
+            | DoubleColon(pos)
+            | Ident(pos)
+            | MalformedPattern(pos)
+            | QualifiedTag(pos)
+            | BackpassComma(pos)
+            | BackpassArrow(pos)
+            | BackpassContinue(pos)
+            | DbgContinue(pos)
+            | When(_, pos)
+            | If(_, pos)
+            | Expect(_, pos)
+            | Dbg(_, pos)
+            | Import(_, pos)
+            | Closure(_, pos)
+            | Underscore(pos)
+            | Crash(pos)
+            | InParens(_, pos)
+            | Record(_, pos)
+            | Str(_, pos)
+            | Number(_, pos)
+            | List(_, pos)
+            | IndentStart(pos)
+            | IndentEnd(pos)
+            | UnexpectedComma(pos)
+            | UnexpectedTopLevelExpr(pos) => *pos,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ESpread {
+    /// A spread on its own in parens, e.g. `(..x)`
+    InParens,
+    /// A spread with three dots, e.g. `[...x]`
+    ThreeDots,
+    /// A spread of more than a single term, e.g. `[..f x]`
+    NeedsParens,
+    /// A `..` that is not directly followed by a value, e.g. `[1, ..]`
+    MissingValue,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ENumber {
     End,
+    /// A decimal literal with a redundant leading zero, e.g. `007`
+    LeadingZero(Region),
+    /// A hex float literal without its binary exponent, e.g. `0x1.8` instead of `0x1.8p0`
+    HexFloatWithoutExponent(Region),
+    /// A digit that is not allowed in the literal's base, e.g. the `2` in `0b12`
+    InvalidDigit(crate::ast::Base, Region),
+    /// An underscore that isn't followed by a digit, e.g. in `1_` or `1__2`.
+    /// Only reported by [crate::number_literal::number_literal_strict].
+    MisplacedUnderscore(Region),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -380,6 +487,12 @@ pub enum EString<'a> {
     EndlessMultiLine(Position),
     EndlessSingleQuote(Position),
     UnknownEscape(Position),
+    /// A `\x` that is not followed by two hex digits, e.g. `"\xZ"`
+    ByteEscapeDigits(Region),
+    /// A `\x` escape for a byte that is not ASCII, e.g. `"\xFF"`
+    NonAsciiByteEscape(Region),
+    /// Several of the escape problems above in one string, e.g. `"\q\xZ"`
+    BadEscapes(&'a [EString<'a>]),
     Format(&'a EExpr<'a>, Position),
     FormatEnd(Position),
     MultilineInsufficientIndent(Position),
@@ -397,14 +510,21 @@ pub enum ESingleQuote {
 pub enum ERecord<'a> {
     End(Position),
     Open(Position),
+    /// A comma with no field before it, e.g. `{ , x }`
+    UnexpectedComma(Region),
 
     Prefix(Position),
     Field(Position),
+    /// A field name that's an expression rather than a plain name, e.g. `{ (x): 1 }`
+    /// or `{ "x": 1 }`
+    NonIdentifierField(Position),
     UnderscoreField(Position),
     Colon(Position),
     QuestionMark(Position),
     Arrow(Position),
     Ampersand(Position),
+    /// A `&&` after the record being updated, instead of a single `&`, e.g. `{ r && x: 1 }`
+    DoubleAmpersand(Region),
 
     // TODO remove
     Expr(&'a EExpr<'a>, Position),
@@ -440,12 +560,20 @@ pub enum EClosure<'a> {
     IndentArrow(Position),
     IndentBody(Position),
     IndentArg(Position),
+    /// An or-pattern in closure params, e.g. `\Red | Green -> ...`, only allowed in `when`
+    OrPattern(Position),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EList<'a> {
     Open(Position),
     End(Position),
+    /// A comma with no element before it, e.g. `[, 1]`
+    UnexpectedComma(Region),
+    /// A list that separates some elements with commas, and others with newlines
+    MixedSeparators(Position),
+    /// A newline-separated list element that doesn't line up with the first one
+    MisalignedElement(Position),
     Space(BadInputError, Position),
 
     Expr(&'a EExpr<'a>, Position),
@@ -459,6 +587,8 @@ pub enum EWhen<'a> {
     Pattern(EPattern<'a>, Position),
     Arrow(Position),
     Bar(Position),
+    /// A `||` between the alternatives of a branch, instead of a single `|`
+    DoubleBar(Region),
 
     IfToken(Position),
     IfGuard(&'a EExpr<'a>, Position),
@@ -467,11 +597,14 @@ pub enum EWhen<'a> {
     Branch(&'a EExpr<'a>, Position),
 
     IndentCondition(Position),
+    IndentIs(Position),
     IndentPattern(Position),
     IndentArrow(Position),
     IndentBranch(Position),
     IndentIfGuard(Position),
     PatternAlignment(u32, Position),
+    /// A `when ... is` that isn't followed by any branches
+    NoBranches(Region),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -530,6 +663,7 @@ pub enum EImport<'a> {
     Exposing(Position),
     ExposingListStart(Position),
     ExposedName(Position),
+    ExposedAliasCase(Region),
     ExposingListEnd(Position),
     IndentIngestedPath(Position),
     IngestedPath(Position),
@@ -630,6 +764,8 @@ pub enum EType<'a> {
     TStart(Position),
     TEnd(Position),
     TFunctionArgument(Position),
+    /// A `|` after a function's return type that isn't followed by an effect, e.g. `a -> b |`
+    TFunctionEffect(Position),
     TWhereBar(Position),
     TImplementsClause(Position),
     TAbilityImpl(ETypeAbilityImpl<'a>, Position),
@@ -661,6 +797,10 @@ pub enum ETypeRecord<'a> {
 pub enum ETypeTagUnion<'a> {
     End(Position),
     Open(Position),
+    /// A `|` that isn't followed by a tag, e.g. the last one in `[| A |]`
+    PipeWithoutTag(Position),
+    /// A tag union that uses both `,` and `|` to separate its tags
+    MixedSeparators(Position),
 
     Type(&'a EType<'a>, Position),
 
@@ -729,8 +869,9 @@ impl<'a> From<ERecord<'a>> for ETypeAbilityImpl<'a> {
     fn from(e: ERecord<'a>) -> Self {
         match e {
             ERecord::End(p) => ETypeAbilityImpl::End(p),
+            ERecord::UnexpectedComma(r) => ETypeAbilityImpl::End(r.start()),
             ERecord::Open(p) => ETypeAbilityImpl::Open(p),
-            ERecord::Field(p) => ETypeAbilityImpl::Field(p),
+            ERecord::Field(p) | ERecord::NonIdentifierField(p) => ETypeAbilityImpl::Field(p),
             ERecord::UnderscoreField(p) => ETypeAbilityImpl::UnderscoreField(p),
             ERecord::Colon(p) => ETypeAbilityImpl::Colon(p),
             ERecord::Arrow(p) => ETypeAbilityImpl::Arrow(p),
@@ -738,6 +879,7 @@ impl<'a> From<ERecord<'a>> for ETypeAbilityImpl<'a> {
             ERecord::Prefix(p) => ETypeAbilityImpl::Prefix(p),
             ERecord::QuestionMark(p) => ETypeAbilityImpl::QuestionMark(p),
             ERecord::Ampersand(p) => ETypeAbilityImpl::Ampersand(p),
+            ERecord::DoubleAmpersand(r) => ETypeAbilityImpl::Ampersand(r.start()),
             ERecord::Expr(e, p) => ETypeAbilityImpl::Expr(e, p),
         }
     }
@@ -904,55 +1046,6 @@ where
     }
 }
 
-/// Apply transform function to turn output of given parser into another parser.
-/// Can be used to chain two parsers.
-///
-/// # Examples
-///
-/// ```
-/// # #![forbid(unused_imports)]
-/// # use roc_parse::state::State;
-/// # use crate::roc_parse::parser::{Parser, Progress, and_then, word};
-/// # use roc_region::all::Position;
-/// # use bumpalo::Bump;
-/// # #[derive(Debug, PartialEq)]
-/// # enum Problem {
-/// #     NotFound(Position),
-/// # }
-/// # let arena = Bump::new();
-/// let parser1 = word("hello", Problem::NotFound);
-/// let parser = and_then(parser1, move |p,b| word(", ", Problem::NotFound));
-///
-/// // Success case
-/// let (progress, output, state) = parser.parse(&arena, State::new("hello, world".as_bytes()), 0).unwrap();
-/// assert_eq!(progress, Progress::MadeProgress);
-/// assert_eq!(output, ());
-/// assert_eq!(state.pos(), Position::new(7));
-///
-/// // Error case
-/// let (progress, problem) = parser.parse(&arena, State::new("hello!! world".as_bytes()), 0).unwrap_err();
-/// assert_eq!(progress, Progress::NoProgress);
-/// assert_eq!(problem, Problem::NotFound(Position::new(5)));
-/// ```
-pub fn and_then<'a, P1, P2, F, Before, After, Error>(
-    parser: P1,
-    transform: F,
-) -> impl Parser<'a, After, Error>
-where
-    P1: Parser<'a, Before, Error>,
-    P2: Parser<'a, After, Error>,
-    F: Fn(Progress, Before) -> P2,
-    Error: 'a,
-{
-    move |arena, state, min_indent| {
-        parser
-            .parse(arena, state, min_indent)
-            .and_then(|(progress, output, next_state)| {
-                transform(progress, output).parse(arena, next_state, min_indent)
-            })
-    }
-}
-
 /// Creates a new parser that can change its output based on a function.
 ///
 /// # Examples
@@ -1000,6 +1093,47 @@ where
     }
 }
 
+/// Creates a new parser that checks the output of `parser` with a fallible function,
+/// such as a range check on a parsed number. Progress is kept from `parser`, and the
+/// error is whatever `f` returns, so it can point at the value that was rejected.
+///
+/// # Examples
+/// ```
+/// # #![forbid(unused_imports)]
+/// # use roc_parse::state::State;
+/// # use crate::roc_parse::parser::{Parser, Progress, and_then, loc, word};
+/// # use roc_region::all::{Position, Region};
+/// # use bumpalo::Bump;
+/// # #[derive(Debug, PartialEq)]
+/// # enum Problem {
+/// #     NotFound(Position),
+/// #     Rude(Region),
+/// # }
+/// # let arena = Bump::new();
+/// let parser = and_then(loc(word("hello", Problem::NotFound)), |loc_word| {
+///     Err::<(), _>(Problem::Rude(loc_word.region))
+/// });
+///
+/// let (progress, problem) = parser.parse(&arena, State::new("hello, world".as_bytes()), 0).unwrap_err();
+/// assert_eq!(progress, Progress::MadeProgress);
+/// assert_eq!(problem, Problem::Rude(Region::new(Position::new(0), Position::new(5))));
+/// ```
+pub fn and_then<'a, P, F, T, U, E>(parser: P, f: F) -> impl Parser<'a, U, E>
+where
+    P: Parser<'a, T, E>,
+    F: Fn(T) -> Result<U, E>,
+    E: 'a,
+{
+    move |arena, state, min_indent| {
+        let (progress, output, state) = parser.parse(arena, state, min_indent)?;
+
+        match f(output) {
+            Ok(output) => Ok((progress, output, state)),
+            Err(fail) => Err((progress, fail)),
+        }
+    }
+}
+
 /// Matches a word/string exactly, useful when finding a keyword.
 /// This only matches if the next char is whitespace, the start of a comment, or the end of a line.
 ///
@@ -1361,6 +1495,52 @@ where
     }
 }
 
+/// Like [`optional`], but gives back `default` when the given parser doesn't
+/// consume anything.
+///
+/// # Examples
+/// ```
+/// # #![forbid(unused_imports)]
+/// # use roc_parse::state::State;
+/// # use crate::roc_parse::parser::{Parser, Progress, map, optional_with_default, word};
+/// # use roc_region::all::Position;
+/// # use bumpalo::Bump;
+/// # #[derive(Debug, PartialEq)]
+/// # enum Problem {
+/// #     NotFound(Position),
+/// # }
+/// # let arena = Bump::new();
+/// let parser = optional_with_default(map(word("hello", Problem::NotFound), |()| "hello"), "bye");
+///
+/// // Parser completed case
+/// let (progress, output, state) = parser.parse(&arena, State::new("hello, world".as_bytes()), 0).unwrap();
+/// assert_eq!(progress, Progress::MadeProgress);
+/// assert_eq!(output, "hello");
+/// assert_eq!(state.pos().offset, 5);
+///
+/// // No progress case
+/// let (progress, output, state) = parser.parse(&arena, State::new("bye, world".as_bytes()), 0).unwrap();
+/// assert_eq!(progress, Progress::NoProgress);
+/// assert_eq!(output, "bye");
+/// assert_eq!(state.pos().offset, 0);
+/// ```
+pub fn optional_with_default<'a, P, T, E>(parser: P, default: T) -> impl Parser<'a, T, E>
+where
+    P: Parser<'a, T, E>,
+    T: Clone,
+    E: 'a,
+{
+    move |arena: &'a Bump, state: State<'a>, min_indent: u32| {
+        let original_state = state.clone();
+
+        match parser.parse(arena, state, min_indent) {
+            Ok((progress, out1, state)) => Ok((progress, out1, state)),
+            Err((MadeProgress, e)) => Err((MadeProgress, e)),
+            Err((NoProgress, _)) => Ok((NoProgress, default.clone(), original_state)),
+        }
+    }
+}
+
 // MACRO COMBINATORS
 //
 // Using some combinators together results in combinatorial type explosion,
@@ -2644,3 +2824,49 @@ where
         Err((_, f)) => Err((NoProgress, f)),
     }
 }
+
+/// Creates a new parser that runs the given parser as lookahead: it forwards the
+/// output or error, but never consumes any input and never makes progress.
+///
+/// # Example
+///
+/// ```
+/// # #![forbid(unused_imports)]
+/// # use roc_parse::state::State;
+/// # use crate::roc_parse::parser::{Parser, Progress, word, peek};
+/// # use roc_region::all::Position;
+/// # use bumpalo::Bump;
+/// # #[derive(Debug, PartialEq)]
+/// # enum Problem {
+/// #     NotFound(Position),
+/// # }
+/// # let arena = Bump::new();
+/// let parser = peek(
+///     word("hello", Problem::NotFound),
+/// );
+///
+/// // Success case
+/// let (progress, output, state) = parser.parse(&arena, State::new("hello, world".as_bytes()), 0).unwrap();
+/// assert_eq!(progress, Progress::NoProgress);
+/// assert_eq!(output, ());
+/// assert_eq!(state.pos(), Position::zero());
+///
+/// // Error Case
+/// let (progress, err) = parser.parse(&arena, State::new("bye, world".as_bytes()), 0).unwrap_err();
+/// assert_eq!(progress, Progress::NoProgress);
+/// assert_eq!(err, Problem::NotFound(Position::zero()));
+/// ```
+pub fn peek<'a, P, Val, Error>(parser: P) -> impl Parser<'a, Val, Error>
+where
+    P: Parser<'a, Val, Error>,
+    Error: 'a,
+{
+    move |arena: &'a Bump, state: State<'a>, min_indent: u32| match parser.parse(
+        arena,
+        state.clone(),
+        min_indent,
+    ) {
+        Ok((_, a, _)) => Ok((NoProgress, a, state)),
+        Err((_, f)) => Err((NoProgress, f)),
+    }
+}
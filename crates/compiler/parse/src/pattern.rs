@@ -1,4 +1,4 @@
-use crate::ast::{Collection, Implements, Pattern, PatternAs, Spaceable};
+use crate::ast::{Collection, CommentOrNewline, Implements, Pattern, PatternAs, Spaceable};
 use crate::blankspace::{space0_e, spaces, spaces_before};
 use crate::ident::{lowercase_ident, parse_ident, Accessor, Ident};
 use crate::keyword;
@@ -29,7 +29,8 @@ pub enum PatternType {
 }
 
 pub fn closure_param<'a>() -> impl Parser<'a, Loc<Pattern<'a>>, EPattern<'a>> {
-    one_of!(
+    // Any param can also be bound whole with `as`, e.g. \{ x } as point -> ...
+    loc_pattern_with_as(one_of!(
         // An ident is the most common param, e.g. \foo -> ...
         loc_ident_pattern_help(true),
         // Underscore is also common, e.g. \_ -> ...
@@ -42,12 +43,19 @@ pub fn closure_param<'a>() -> impl Parser<'a, Loc<Pattern<'a>>, EPattern<'a>> {
         // If you wrap it in parens, you can match any arbitrary pattern at all.
         // e.g. \User.UserId userId -> ...
         specialize_err(EPattern::PInParens, loc_pattern_in_parens_help())
-    )
+    ))
 }
 
 pub fn loc_pattern_help<'a>() -> impl Parser<'a, Loc<Pattern<'a>>, EPattern<'a>> {
+    loc_pattern_with_as(loc_pattern_help_help(true))
+}
+
+/// A pattern, optionally followed by `as` and a name for the whole value, e.g. `{ x } as point`
+fn loc_pattern_with_as<'a>(
+    parser: impl Parser<'a, Loc<Pattern<'a>>, EPattern<'a>>,
+) -> impl Parser<'a, Loc<Pattern<'a>>, EPattern<'a>> {
     move |arena, state: State<'a>, min_indent| {
-        let (_, pattern, state) = loc_pattern_help_help(true).parse(arena, state, min_indent)?;
+        let (_, pattern, state) = parser.parse(arena, state, min_indent)?;
 
         let pattern_state = state.clone();
 
@@ -63,23 +71,33 @@ pub fn loc_pattern_help<'a>() -> impl Parser<'a, Loc<Pattern<'a>>, EPattern<'a>>
                 NoProgress => Ok((MadeProgress, pattern, pattern_state)),
             },
             Ok((_, pattern_as, state)) => {
-                let region = Region::span_across(&pattern.region, &pattern_as.identifier.region);
-
-                let mut pattern = pattern;
-                if !pattern_spaces.is_empty() {
-                    pattern = arena
-                        .alloc(pattern.value)
-                        .with_spaces_after(pattern_spaces, pattern.region)
-                }
+                let as_pattern = with_pattern_as(arena, pattern, pattern_spaces, pattern_as);
 
-                let as_pattern = Pattern::As(arena.alloc(pattern), pattern_as);
-
-                Ok((MadeProgress, Loc::at(region, as_pattern), state))
+                Ok((MadeProgress, as_pattern, state))
             }
         }
     }
 }
 
+/// Wrap a pattern in `Pattern::As`, keeping the spaces between the pattern and the `as`.
+pub(crate) fn with_pattern_as<'a>(
+    arena: &'a Bump,
+    pattern: Loc<Pattern<'a>>,
+    pattern_spaces: &'a [CommentOrNewline<'a>],
+    pattern_as: PatternAs<'a>,
+) -> Loc<Pattern<'a>> {
+    let region = Region::span_across(&pattern.region, &pattern_as.identifier.region);
+
+    let mut pattern = pattern;
+    if !pattern_spaces.is_empty() {
+        pattern = arena
+            .alloc(pattern.value)
+            .with_spaces_after(pattern_spaces, pattern.region)
+    }
+
+    Loc::at(region, Pattern::As(arena.alloc(pattern), pattern_as))
+}
+
 fn loc_pattern_help_help<'a>(
     can_have_arguments: bool,
 ) -> impl Parser<'a, Loc<Pattern<'a>>, EPattern<'a>> {
@@ -97,7 +115,7 @@ fn loc_pattern_help_help<'a>(
     )
 }
 
-fn pattern_as<'a>() -> impl Parser<'a, PatternAs<'a>, EPattern<'a>> {
+pub(crate) fn pattern_as<'a>() -> impl Parser<'a, PatternAs<'a>, EPattern<'a>> {
     move |arena, state: State<'a>, min_indent| {
         let (_, _, state) =
             parser::keyword(keyword::AS, EPattern::AsKeyword).parse(arena, state, min_indent)?;
@@ -195,6 +213,8 @@ pub fn loc_implements_parser<'a>() -> impl Parser<'a, Loc<Implements<'a>>, EPatt
     )
 }
 
+/// A tuple pattern, or any pattern wrapped in parens. Like parenthesized expressions, a
+/// parenthesized pattern keeps its own region, which doesn't include the parens.
 fn loc_pattern_in_parens_help<'a>() -> impl Parser<'a, Loc<Pattern<'a>>, PInParens<'a>> {
     then(
         loc(collection_trailing_sep_e(
@@ -92,6 +92,13 @@ impl<'a> State<'a> {
         self
     }
 
+    /// The same state, but with the input cut off at `end`, so parsing stops there
+    #[must_use]
+    pub(crate) fn truncate(mut self, end: Position) -> State<'a> {
+        self.original_bytes = &self.original_bytes[..end.offset as usize];
+        self
+    }
+
     #[must_use]
     #[inline(always)]
     pub(crate) const fn advance_newline(mut self) -> State<'a> {
@@ -8,6 +8,8 @@ use crate::parser::{
 use crate::state::State;
 use bumpalo::collections::vec::Vec;
 use bumpalo::Bump;
+use roc_error_macros::internal_error;
+use roc_region::all::Region;
 
 /// One or more ASCII hex digits. (Useful when parsing unicode escape codes,
 /// which must consist entirely of ASCII hex digits.)
@@ -32,6 +34,16 @@ fn ascii_hex_digits<'a>() -> impl Parser<'a, &'a str, EString<'a>> {
     }
 }
 
+/// The value of a single ASCII hex digit
+fn hex_digit_value(digit: u8) -> u8 {
+    match digit {
+        b'0'..=b'9' => digit - b'0',
+        b'a'..=b'f' => digit - b'a' + 10,
+        b'A'..=b'F' => digit - b'A' + 10,
+        _ => internal_error!("{} is not a hex digit", digit as char),
+    }
+}
+
 fn consume_indent(mut state: State, mut indent: u32) -> Result<State, (Progress, EString)> {
     while indent > 0 {
         match state.bytes().first() {
@@ -173,6 +185,25 @@ pub fn parse_str_like_literal<'a>() -> impl Parser<'a, StrLikeLiteral<'a>, EStri
             };
         }
 
+        // Escapes we can skip over are collected, so that every one of them
+        // is reported once the string is closed, rather than just the first
+        let mut bad_escapes: Vec<'a, EString<'a>> = Vec::new_in(arena);
+
+        macro_rules! check_bad_escapes {
+            () => {
+                match bad_escapes.as_slice() {
+                    [] => {}
+                    [bad_escape] => return Err((MadeProgress, bad_escape.clone())),
+                    _ => {
+                        return Err((
+                            MadeProgress,
+                            EString::BadEscapes(bad_escapes.into_bump_slice()),
+                        ))
+                    }
+                }
+            };
+        }
+
         let mut preceded_by_dollar = false;
 
         while let Some(&one_byte) = bytes.next() {
@@ -185,6 +216,8 @@ pub fn parse_str_like_literal<'a>() -> impl Parser<'a, StrLikeLiteral<'a>, EStri
                         // special case of the empty string
                         if is_multiline {
                             if bytes.as_slice().starts_with(b"\"\"") {
+                                check_bad_escapes!();
+
                                 return Ok((
                                     MadeProgress,
                                     StrLikeLiteral::Str(StrLiteral::Block(&[])),
@@ -197,6 +230,8 @@ pub fn parse_str_like_literal<'a>() -> impl Parser<'a, StrLikeLiteral<'a>, EStri
                         } else {
                             // This is the end of the string!
                             // Advance 1 for the close quote
+                            check_bad_escapes!();
+
                             return Ok((
                                 MadeProgress,
                                 StrLikeLiteral::Str(StrLiteral::PlainLine("")),
@@ -208,6 +243,7 @@ pub fn parse_str_like_literal<'a>() -> impl Parser<'a, StrLikeLiteral<'a>, EStri
                         // and the current segment into a string literal
                         if is_multiline {
                             if bytes.as_slice().starts_with(b"\"\"") {
+                                check_bad_escapes!();
                                 end_segment!(StrSegment::Plaintext);
 
                                 let expr = if segments.len() == 1 {
@@ -233,6 +269,7 @@ pub fn parse_str_like_literal<'a>() -> impl Parser<'a, StrLikeLiteral<'a>, EStri
                                 continue;
                             }
                         } else {
+                            check_bad_escapes!();
                             end_segment!(StrSegment::Plaintext);
 
                             let expr = if segments.len() == 1 {
@@ -252,6 +289,7 @@ pub fn parse_str_like_literal<'a>() -> impl Parser<'a, StrLikeLiteral<'a>, EStri
                     };
                 }
                 b'\'' if is_single_quote => {
+                    check_bad_escapes!();
                     end_segment!(StrSegment::Plaintext);
 
                     let expr = if segments.len() == 1 {
@@ -378,7 +416,9 @@ pub fn parse_str_like_literal<'a>() -> impl Parser<'a, StrLikeLiteral<'a>, EStri
                                 loc(ascii_hex_digits()),
                                 byte(b')', EString::CodePtEnd),
                             )
-                            .parse(arena, state, min_indent)?;
+                            .parse(arena, state, min_indent)
+                            // We're inside the string, so nothing else can parse it instead
+                            .map_err(|(_, fail)| (MadeProgress, fail))?;
 
                             // Advance the iterator past the expr we just parsed.
                             for _ in 0..(original_byte_count - new_state.bytes().len()) {
@@ -412,11 +452,64 @@ pub fn parse_str_like_literal<'a>() -> impl Parser<'a, StrLikeLiteral<'a>, EStri
                         Some(b'$') => {
                             escaped_char!(EscapedChar::Dollar);
                         }
-                        _ => {
+                        Some(b'0') => {
+                            escaped_char!(EscapedChar::Null);
+                        }
+                        Some(b'x') => {
+                            // A byte escape like `\x41` must have exactly two hex digits
+                            let escape_start = state.pos();
+                            let digits = &state.bytes()[2..];
+                            let digit_count = digits
+                                .iter()
+                                .take(2)
+                                .take_while(|byte| byte.is_ascii_hexdigit())
+                                .count();
+
+                            if digit_count < 2 {
+                                let region = Region::new(
+                                    escape_start,
+                                    escape_start.bump_column(2 + digit_count as u32),
+                                );
+                                bad_escapes.push(EString::ByteEscapeDigits(region));
+
+                                // Skip the `\x`, and carry on from whatever follows it
+                                state.advance_mut(2);
+                                segment_parsed_bytes = 0;
+                            } else {
+                                let value =
+                                    hex_digit_value(digits[0]) * 16 + hex_digit_value(digits[1]);
+
+                                // Advance the iterator past the two digits
+                                bytes.next();
+                                bytes.next();
+                                segment_parsed_bytes += 2;
+
+                                if value.is_ascii() {
+                                    escaped_char!(EscapedChar::Byte(value));
+                                } else {
+                                    let region =
+                                        Region::new(escape_start, escape_start.bump_column(4));
+                                    bad_escapes.push(EString::NonAsciiByteEscape(region));
+
+                                    state.advance_mut(segment_parsed_bytes);
+                                    segment_parsed_bytes = 0;
+                                }
+                            }
+                        }
+                        Some(b'\n') | None => {
+                            // There's nothing after the backslash to skip over
+                            return Err((MadeProgress, EString::UnknownEscape(state.pos())));
+                        }
+                        Some(_) => {
                             // Invalid escape! A backslash must be followed
                             // by one of these escapable characters:
                             // (\n, \t, \", \\, etc)
-                            return Err((MadeProgress, EString::UnknownEscape(state.pos())));
+                            bad_escapes.push(EString::UnknownEscape(state.pos()));
+
+                            // Skip the backslash, and keep the character after it
+                            // as the start of the next segment
+                            state.advance_mut(1);
+                            segment_parsed_bytes = 1;
                         }
                     }
                 }
@@ -460,7 +553,8 @@ pub fn parse_str_like_literal<'a>() -> impl Parser<'a, StrLikeLiteral<'a>, EStri
                         ),
                         byte(b')', EString::FormatEnd),
                     )
-                    .parse(arena, state, min_indent)?;
+                    .parse(arena, state, min_indent)
+                    .map_err(|(_, fail)| (MadeProgress, fail))?;
 
                     // Advance the iterator past the expr we just parsed.
                     for _ in 0..(original_byte_count - new_state.bytes().len()) {
@@ -1,11 +1,12 @@
 use crate::ast::{
-    AbilityImpls, AssignedField, CommentOrNewline, Expr, ImplementsAbilities, ImplementsAbility,
-    ImplementsClause, Pattern, Spaceable, Spaced, Tag, TypeAnnotation, TypeHeader,
+    AbilityImpls, AssignedField, Collection, CommentOrNewline, Expr, ImplementsAbilities,
+    ImplementsAbility, ImplementsClause, Pattern, Spaceable, Spaced, Tag, TypeAnnotation,
+    TypeHeader,
 };
 use crate::blankspace::{
-    space0_around_ee, space0_before_e, space0_before_optional_after, space0_e,
+    space0_around_ee, space0_before_e, space0_before_optional_after, space0_e, spaces,
 };
-use crate::expr::record_field;
+use crate::expr::{merge_spaces, record_field};
 use crate::ident::{lowercase_ident, lowercase_ident_keyword_e};
 use crate::keyword;
 use crate::parser::{
@@ -39,15 +40,26 @@ pub fn located_opaque_signature<'a>(
 fn tag_union_type<'a>(
     stop_at_surface_has: bool,
 ) -> impl Parser<'a, TypeAnnotation<'a>, ETypeTagUnion<'a>> {
-    move |arena, state, min_indent| {
-        let (_, tags, state) = collection_trailing_sep_e(
-            byte(b'[', ETypeTagUnion::Open),
-            loc(tag_type(false)),
-            byte(b',', ETypeTagUnion::End),
-            byte(b']', ETypeTagUnion::End),
-            Tag::SpaceBefore,
-        )
-        .parse(arena, state, min_indent)?;
+    move |arena, state: State<'a>, min_indent: u32| {
+        let (_, tags, state) = match one_of![
+            pipe_separated_tags(),
+            collection_trailing_sep_e(
+                byte(b'[', ETypeTagUnion::Open),
+                loc(tag_type(false)),
+                byte(b',', ETypeTagUnion::End),
+                byte(b']', ETypeTagUnion::End),
+                Tag::SpaceBefore,
+            )
+        ]
+        .parse(arena, state.clone(), min_indent)
+        {
+            Ok(ok) => ok,
+            Err((progress, ETypeTagUnion::End(pos))) if byte_at(&state, pos) == Some(b'|') => {
+                // e.g. the `|` in `[A, | B]`
+                return Err((progress, ETypeTagUnion::MixedSeparators(pos)));
+            }
+            Err(err) => return Err(err),
+        };
 
         // This could be an open tag union, e.g. `[Foo, Bar]a`
         let (_, ext, state) = optional(allocated(specialize_err_ref(
@@ -62,6 +74,89 @@ fn tag_union_type<'a>(
     }
 }
 
+fn byte_at(state: &State, pos: Position) -> Option<u8> {
+    let index = pos.offset.checked_sub(state.pos().offset)?;
+
+    state.bytes().get(index as usize).copied()
+}
+
+/// The tags of a tag union written with a `|` before each one, e.g.
+///
+/// ```roc
+/// [
+///     | Red
+///     | Green
+/// ]
+/// ```
+///
+/// Unlike the comma-separated form, there's no trailing `|`.
+fn pipe_separated_tags<'a>() -> impl Parser<'a, Collection<'a, Loc<Tag<'a>>>, ETypeTagUnion<'a>> {
+    move |arena, state: State<'a>, _min_indent: u32| {
+        // Like other collections, the tags can be indented any amount
+        let min_indent = 0;
+
+        let (_, (), state) = byte(b'[', ETypeTagUnion::Open).parse(arena, state, min_indent)?;
+        let (_, mut spaces_before, mut state) = spaces().parse(arena, state, min_indent)?;
+
+        if state.bytes().first() != Some(&b'|') {
+            return Err((NoProgress, ETypeTagUnion::Open(state.pos())));
+        }
+
+        let mut tags = Vec::new_in(arena);
+
+        let final_comments = loop {
+            let pipe_pos = state.pos();
+            state.advance_mut(1);
+
+            let (_, spaces_after_pipe, next_state) = spaces()
+                .parse(arena, state, min_indent)
+                .map_err(|(_, fail)| (MadeProgress, fail))?;
+            let (_, mut tag, next_state) =
+                match loc(tag_type(false)).parse(arena, next_state.clone(), min_indent) {
+                    Ok(ok) => ok,
+                    Err((NoProgress, _))
+                        if !next_state
+                            .bytes()
+                            .first()
+                            .is_some_and(u8::is_ascii_alphabetic) =>
+                    {
+                        // e.g. the trailing `|` in `[| A |]`
+                        return Err((MadeProgress, ETypeTagUnion::PipeWithoutTag(pipe_pos)));
+                    }
+                    Err((_, fail)) => return Err((MadeProgress, fail)),
+                };
+
+            let spaces_before_tag = merge_spaces(arena, spaces_before, spaces_after_pipe);
+            if !spaces_before_tag.is_empty() {
+                tag.value = Tag::SpaceBefore(arena.alloc(tag.value), spaces_before_tag);
+            }
+            tags.push(tag);
+
+            let (_, spaces_after_tag, next_state) = spaces()
+                .parse(arena, next_state, min_indent)
+                .map_err(|(_, fail)| (MadeProgress, fail))?;
+            state = next_state;
+
+            match state.bytes().first() {
+                Some(b'|') => spaces_before = spaces_after_tag,
+                Some(b',') => {
+                    return Err((MadeProgress, ETypeTagUnion::MixedSeparators(state.pos())));
+                }
+                _ => break spaces_after_tag,
+            }
+        };
+
+        let (_, (), state) = byte(b']', ETypeTagUnion::End)
+            .parse(arena, state, min_indent)
+            .map_err(|(_, fail)| (MadeProgress, fail))?;
+
+        let tags =
+            Collection::with_items_and_comments(arena, tags.into_bump_slice(), final_comments);
+
+        Ok((MadeProgress, tags, state))
+    }
+}
+
 fn check_type_alias<'a>(
     arena: &'a Bump,
     annot: Loc<TypeAnnotation<'a>>,
@@ -335,10 +430,12 @@ fn record_type_field<'a>() -> impl Parser<'a, AssignedField<'a, TypeAnnotation<'
 
         let val_parser = specialize_err_ref(ETypeRecord::Type, expression(true, false));
 
+        // Once we have seen a `:` or `?`, the field must have a type
         match opt_loc_val {
             Some(First(_)) => {
                 let (_, loc_val, state) = space0_before_e(val_parser, ETypeRecord::IndentColon)
-                    .parse(arena, state, min_indent)?;
+                    .parse(arena, state, min_indent)
+                    .map_err(|(_, fail)| (MadeProgress, fail))?;
 
                 Ok((
                     MadeProgress,
@@ -348,7 +445,8 @@ fn record_type_field<'a>() -> impl Parser<'a, AssignedField<'a, TypeAnnotation<'
             }
             Some(Second(_)) => {
                 let (_, loc_val, state) = space0_before_e(val_parser, ETypeRecord::IndentOptional)
-                    .parse(arena, state, min_indent)?;
+                    .parse(arena, state, min_indent)
+                    .map_err(|(_, fail)| (MadeProgress, fail))?;
 
                 Ok((
                     MadeProgress,
@@ -576,6 +674,49 @@ fn ability_impl_field<'a>() -> impl Parser<'a, AssignedField<'a, Expr<'a>>, ERec
 fn expression<'a>(
     is_trailing_comma_valid: bool,
     stop_at_surface_has: bool,
+) -> impl Parser<'a, Loc<TypeAnnotation<'a>>, EType<'a>> {
+    (move |arena, state: State<'a>, min_indent: u32| {
+        let (progress, annot, state) = function_type(is_trailing_comma_valid, stop_at_surface_has)
+            .parse(arena, state, min_indent)?;
+
+        // Finally, try to parse a where clause if there is one.
+        // The where clause must be at least as deep as where the type annotation started.
+        match implements_clause_chain().parse(arena, state.clone(), min_indent) {
+            Ok((where_progress, (spaces_before, implements_chain), state)) => {
+                let region =
+                    Region::span_across(&annot.region, &implements_chain.last().unwrap().region);
+                let type_annot = if !spaces_before.is_empty() {
+                    // We're transforming the spaces_before the 'where'
+                    // into spaces_after the thing before the 'where'
+                    let spaced = arena
+                        .alloc(annot.value)
+                        .with_spaces_after(spaces_before, annot.region);
+                    &*arena.alloc(spaced)
+                } else {
+                    &*arena.alloc(annot)
+                };
+                let where_annot = TypeAnnotation::Where(type_annot, implements_chain);
+                Ok((
+                    where_progress.or(progress),
+                    Loc::at(region, where_annot),
+                    state,
+                ))
+            }
+            Err(_) => {
+                // Ran into a problem parsing a where clause; don't suppose there is one.
+                Ok((progress, annot, state))
+            }
+        }
+    })
+    .trace("type_annotation:expression")
+}
+
+/// Parse a type that may be a function type. The arrow is right-associative,
+/// so `a -> b -> c` parses as `a -> (b -> c)`; the return type is parsed here
+/// rather than left behind for the surrounding expression parser.
+fn function_type<'a>(
+    is_trailing_comma_valid: bool,
+    stop_at_surface_has: bool,
 ) -> impl Parser<'a, Loc<TypeAnnotation<'a>>, EType<'a>> {
     (move |arena, state: State<'a>, min_indent: u32| {
         let (p1, first, state) = space0_before_e(term(stop_at_surface_has), EType::TIndentStart)
@@ -583,32 +724,51 @@ fn expression<'a>(
 
         let result = and(
             zero_or_more(skip_first(
-                byte(b',', EType::TFunctionArgument),
-                one_of![
-                    space0_around_ee(
-                        term(stop_at_surface_has),
-                        EType::TIndentStart,
-                        EType::TIndentEnd
-                    ),
-                    fail(EType::TFunctionArgument)
-                ],
+                not_trailing_comma(),
+                skip_first(
+                    byte(b',', EType::TFunctionArgument),
+                    one_of![
+                        space0_around_ee(
+                            term(stop_at_surface_has),
+                            EType::TIndentStart,
+                            EType::TIndentEnd
+                        ),
+                        fail(EType::TFunctionArgument)
+                    ],
+                ),
             ))
             .trace("type_annotation:expression:rest_args"),
-            skip_second(
-                space0_e(EType::TIndentStart),
-                two_bytes(b'-', b'>', EType::TStart),
+            skip_first(
+                // like in lists and records, the last argument may have a trailing comma
+                optional(byte(b',', EType::TStart)),
+                skip_second(
+                    space0_e(EType::TIndentStart),
+                    two_bytes(b'-', b'>', EType::TStart),
+                ),
             )
             .trace("type_annotation:expression:arrow"),
         )
         .parse(arena, state.clone(), min_indent);
 
-        let (progress, annot, state) = match result {
+        match result {
             Ok((p2, (rest, space_before_arrow), state)) => {
-                let (p3, return_type, state) =
-                    space0_before_e(term(stop_at_surface_has), EType::TIndentStart)
-                        .parse(arena, state, min_indent)?;
+                let (p3, mut return_type, state) =
+                    function_type(true, stop_at_surface_has).parse(arena, state, min_indent)?;
+
+                let (p4, effect, state) =
+                    function_effect(stop_at_surface_has).parse(arena, state, min_indent)?;
 
-                let region = Region::span_across(&first.region, &return_type.region);
+                let region = match effect {
+                    Some((space_before_bar, effect)) => {
+                        if !space_before_bar.is_empty() {
+                            return_type.value =
+                                arena.alloc(return_type.value).after(space_before_bar);
+                        }
+
+                        Region::span_across(&first.region, &effect.region)
+                    }
+                    None => Region::span_across(&first.region, &return_type.region),
+                };
 
                 // prepare arguments
                 let mut arguments = Vec::with_capacity_in(rest.len() + 1, arena);
@@ -626,10 +786,14 @@ fn expression<'a>(
 
                 let result = Loc {
                     region,
-                    value: TypeAnnotation::Function(output, arena.alloc(return_type)),
+                    value: TypeAnnotation::Function(
+                        output,
+                        arena.alloc(return_type),
+                        effect.map(|(_, effect)| &*arena.alloc(effect)),
+                    ),
                 };
-                let progress = p1.or(p2).or(p3);
-                (progress, result, state)
+                let progress = p1.or(p2).or(p3).or(p4);
+                Ok((progress, result, state))
             }
             Err(err) => {
                 if !is_trailing_comma_valid {
@@ -650,40 +814,63 @@ fn expression<'a>(
                 }
 
                 // We ran into trouble parsing the function bits; just return the single term
-                (p1, first, state)
-            }
-        };
-
-        // Finally, try to parse a where clause if there is one.
-        // The where clause must be at least as deep as where the type annotation started.
-        match implements_clause_chain().parse(arena, state.clone(), min_indent) {
-            Ok((where_progress, (spaces_before, implements_chain), state)) => {
-                let region =
-                    Region::span_across(&annot.region, &implements_chain.last().unwrap().region);
-                let type_annot = if !spaces_before.is_empty() {
-                    // We're transforming the spaces_before the 'where'
-                    // into spaces_after the thing before the 'where'
-                    let spaced = arena
-                        .alloc(annot.value)
-                        .with_spaces_after(spaces_before, annot.region);
-                    &*arena.alloc(spaced)
-                } else {
-                    &*arena.alloc(annot)
-                };
-                let where_annot = TypeAnnotation::Where(type_annot, implements_chain);
-                Ok((
-                    where_progress.or(progress),
-                    Loc::at(region, where_annot),
-                    state,
-                ))
-            }
-            Err(_) => {
-                // Ran into a problem parsing a where clause; don't suppose there is one.
-                Ok((progress, annot, state))
+                Ok((p1, first, state))
             }
         }
     })
-    .trace("type_annotation:expression")
+    .trace("type_annotation:function_type")
+}
+
+/// Parse the effect that may follow a function's return type, e.g. the `| Effect`
+/// in `Str -> {} | Effect`, along with the spaces before the `|`. Since the return
+/// type is parsed first, `a -> b -> c | E` attaches the effect to `b -> c | E`.
+fn function_effect<'a>(
+    stop_at_surface_has: bool,
+) -> impl Parser<'a, Option<(&'a [CommentOrNewline<'a>], Loc<TypeAnnotation<'a>>)>, EType<'a>> {
+    move |arena, state: State<'a>, min_indent: u32| {
+        let (space_before_bar, after_spaces) =
+            match space0_e(EType::TIndentStart).parse(arena, state.clone(), min_indent) {
+                Ok((_, spaces, after_spaces)) => (spaces, after_spaces),
+                Err(_) => return Ok((NoProgress, None, state)),
+            };
+
+        // `||` and `|>` are operators rather than the start of an effect
+        match after_spaces.bytes() {
+            [b'|', b'|' | b'>', ..] => return Ok((NoProgress, None, state)),
+            [b'|', ..] => {}
+            _ => return Ok((NoProgress, None, state)),
+        }
+
+        let after_bar = after_spaces.advance(1);
+
+        match space0_before_e(term(stop_at_surface_has), EType::TIndentStart).parse(
+            arena,
+            after_bar.clone(),
+            min_indent,
+        ) {
+            Ok((_, effect, state)) => Ok((MadeProgress, Some((space_before_bar, effect)), state)),
+            Err(_) => Err((MadeProgress, EType::TFunctionEffect(after_bar.pos()))),
+        }
+    }
+}
+
+/// Fails without making progress at a comma that's followed by the `->` of a function type,
+/// e.g. the last one in `a, b, -> c`, so that the arrow's parser can pick it up.
+fn not_trailing_comma<'a>() -> impl Parser<'a, (), EType<'a>> {
+    move |arena, state: State<'a>, min_indent: u32| {
+        let trailing_comma = skip_first(
+            byte(b',', EType::TStart),
+            skip_first(
+                space0_e(EType::TIndentStart),
+                two_bytes(b'-', b'>', EType::TStart),
+            ),
+        );
+
+        match trailing_comma.parse(arena, state.clone(), min_indent) {
+            Ok(_) => Err((NoProgress, EType::TFunctionArgument(state.pos()))),
+            Err(_) => Ok((NoProgress, (), state)),
+        }
+    }
 }
 
 /// Parse a basic type annotation that's a combination of variables
@@ -18,15 +18,22 @@ extern crate roc_parse;
 mod test_parse {
     use bumpalo::collections::vec::Vec;
     use bumpalo::{self, Bump};
+    use roc_module::called_via::{BinOp, CalledVia, UnaryOp};
     use roc_parse::ast::Expr::{self, *};
     use roc_parse::ast::StrSegment::*;
-    use roc_parse::ast::{self, EscapedChar};
+    use roc_parse::ast::{self, Collection, EscapedChar, ExtractSpaces};
     use roc_parse::ast::{CommentOrNewline, StrLiteral::*};
-    use roc_parse::header::parse_module_defs;
-    use roc_parse::parser::SyntaxError;
+    use roc_parse::expr::{binop_precedence, parse_expr_partial, Associativity};
+    use roc_parse::header::{parse_module, parse_module_defs};
+    use roc_parse::ident::Accessor;
+    use roc_parse::number_literal::number_literal_strict;
+    use roc_parse::parser::{
+        and, and_then, loc, map, optional_with_default, peek, succeed, word, EExpr, EIf, EImport,
+        ENumber, EString, Parser, Progress, SyntaxError,
+    };
     use roc_parse::state::State;
-    use roc_parse::test_helpers::parse_expr_with;
-    use roc_region::all::{Loc, Region};
+    use roc_parse::test_helpers::{parse_expr_with, parse_loc_with};
+    use roc_region::all::{Loc, Position, Region};
     use std::{f64, i64};
 
     fn assert_parses_to<'a>(input: &'a str, expected_expr: Expr<'a>) {
@@ -67,6 +74,8 @@ mod test_parse {
             ("\\r", EscapedChar::CarriageReturn),
             ("\\t", EscapedChar::Tab),
             ("\\\"", EscapedChar::DoubleQuote),
+            ("\\0", EscapedChar::Null),
+            ("\\x41", EscapedChar::Byte(b'A')),
         ] {
             let actual = parse_expr_with(&arena, arena.alloc(to_input(string)));
             let expected_slice = to_expected(*escaped, &arena);
@@ -156,6 +165,106 @@ mod test_parse {
         });
     }
 
+    #[test]
+    fn unicode_escapes_around_interpolation() {
+        // Neither the escapes nor the interpolation consume each other's parens
+        let x = Var {
+            module_name: "",
+            ident: "x",
+        };
+
+        assert_segments(r#""\u(41)$(x)\u(42)""#, |arena| {
+            bumpalo::vec![in arena;
+                 Unicode(Loc::new(4, 6, "41")),
+                 Interpolated(Loc::new(9, 10, arena.alloc(x))),
+                 Unicode(Loc::new(14, 16, "42"))
+            ]
+        });
+    }
+
+    #[test]
+    fn unicode_escape_without_parens() {
+        let arena = Bump::new();
+
+        assert_eq!(
+            Err(SyntaxError::Expr(
+                EExpr::Str(EString::CodePtOpen(Position::new(3)), Position::new(0)),
+                Position::new(0)
+            )),
+            parse_expr_with(&arena, r#""\u{41}""#)
+        );
+    }
+
+    #[test]
+    fn byte_escape_missing_digits() {
+        let arena = Bump::new();
+        let actual = parse_expr_with(&arena, r#""ab\xZ""#);
+
+        assert_eq!(
+            Err(SyntaxError::Expr(
+                EExpr::Str(
+                    EString::ByteEscapeDigits(Region::new(Position::new(3), Position::new(5))),
+                    Position::new(0)
+                ),
+                Position::new(0)
+            )),
+            actual
+        );
+    }
+
+    #[test]
+    fn every_bad_escape_is_reported() {
+        let arena = Bump::new();
+        let actual = parse_expr_with(&arena, r#""a\qb\xZc""#);
+        let bad_escapes = [
+            EString::UnknownEscape(Position::new(2)),
+            EString::ByteEscapeDigits(Region::new(Position::new(5), Position::new(7))),
+        ];
+
+        assert_eq!(
+            Err(SyntaxError::Expr(
+                EExpr::Str(EString::BadEscapes(&bad_escapes), Position::new(0)),
+                Position::new(0)
+            )),
+            actual
+        );
+    }
+
+    #[test]
+    fn unicode_escape_largest_code_point() {
+        assert_segments(r#""max \u(10FFFF)""#, |arena| {
+            bumpalo::vec![in arena;
+                 Plaintext("max "),
+                 Unicode(Loc::new(8, 14, "10FFFF")),
+            ]
+        });
+    }
+
+    // RAW WHITESPACE
+
+    #[test]
+    fn string_with_raw_tab_and_carriage_return() {
+        // These are kept exactly as written; canonicalization warns about the tab
+        assert_parses_to("\"a\tb\"", Str(PlainLine("a\tb")));
+        assert_parses_to("\"a\rb\"", Str(PlainLine("a\rb")));
+    }
+
+    #[test]
+    fn single_line_string_with_raw_newline() {
+        let arena = Bump::new();
+
+        assert_eq!(
+            Err(SyntaxError::Expr(
+                EExpr::Str(
+                    EString::EndlessSingleLine(Position::new(1)),
+                    Position::new(0)
+                ),
+                Position::new(0)
+            )),
+            parse_expr_with(&arena, "\"a\nb\"")
+        );
+    }
+
     // INTERPOLATION
 
     #[test]
@@ -294,11 +403,216 @@ mod test_parse {
         );
     }
 
+    #[test]
+    fn crash_with_interpolated_message() {
+        // The argument to `crash` is an ordinary string literal, interpolation and all.
+        let arena = Bump::new();
+        let reason = arena.alloc(Var {
+            module_name: "",
+            ident: "reason",
+        });
+        let segments = [
+            Plaintext("failed: "),
+            Interpolated(Loc::new(17, 23, reason)),
+        ];
+        let message = Loc::new(6, 25, Str(Line(&segments)));
+        let args = [&message];
+
+        assert_parses_to(
+            r#"crash "failed: $(reason)""#,
+            Apply(arena.alloc(Loc::new(0, 5, Crash)), &args, CalledVia::Space),
+        );
+    }
+
+    // ADJACENT STRINGS
+
+    #[test]
+    fn adjacent_strings_on_continuation_lines() {
+        assert_parses_to(
+            "\"part one \"\n    \"part two\"",
+            Str(PlainLine("part one part two")),
+        );
+
+        let arena = Bump::new();
+        let name = arena.alloc(Var {
+            module_name: "",
+            ident: "name",
+        });
+        let segments = [
+            Plaintext("Hi, "),
+            Interpolated(Loc::new(14, 18, name)),
+            Plaintext("!"),
+        ];
+
+        assert_parses_to("\"Hi, \"\n    \"$(name)\"\n    \"!\"", Str(Line(&segments)));
+    }
+
+    #[test]
+    fn adjacent_strings_on_one_line_are_not_concatenated() {
+        let arena = Bump::new();
+        let actual = parse_expr_with(&arena, "\"a\" \"b\"");
+
+        match actual {
+            Ok(Apply(string, [arg], CalledVia::Space)) => {
+                assert_eq!(string.value, Str(PlainLine("a")));
+                assert_eq!(arg.value, Str(PlainLine("b")));
+            }
+            other => panic!("Expected a string applied to a string, but got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn strings_separated_by_a_comment_are_not_concatenated() {
+        // Joining them would lose the comment
+        let arena = Bump::new();
+        let actual = parse_expr_with(&arena, "\"a\" # note\n    \"b\"");
+
+        match actual {
+            Ok(Apply(string, [arg], CalledVia::Space)) => {
+                assert_eq!(string.value, Str(PlainLine("a")));
+                assert!(matches!(
+                    arg.value,
+                    SpaceBefore(
+                        Str(PlainLine("b")),
+                        [CommentOrNewline::LineComment(" note"), ..]
+                    )
+                ));
+            }
+            other => panic!("Expected a string applied to a string, but got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn string_then_ident_on_continuation_line() {
+        // Only another string continues the literal; anything else is an ordinary argument
+        let arena = Bump::new();
+        let actual = parse_expr_with(&arena, "\"part one\"\n    foo");
+
+        match actual {
+            Ok(Apply(string, [arg], CalledVia::Space)) => {
+                assert_eq!(string.value, Str(PlainLine("part one")));
+                assert_eq!(
+                    arg.value.extract_spaces().item,
+                    Var {
+                        module_name: "",
+                        ident: "foo"
+                    }
+                );
+            }
+            other => panic!("Expected a string applied to foo, but got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn string_arguments_are_not_concatenated() {
+        let arena = Bump::new();
+        let actual = parse_expr_with(&arena, "Str.concat \"a\"\n    \"b\"");
+
+        match actual {
+            Ok(Apply(_, [a, b], CalledVia::Space)) => {
+                assert_eq!(a.value.extract_spaces().item, Str(PlainLine("a")));
+                assert_eq!(b.value.extract_spaces().item, Str(PlainLine("b")));
+            }
+            other => panic!("Expected two string arguments, but got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn strings_on_separate_lines_in_a_list_are_not_concatenated() {
+        // The second string isn't indented further than the first one's line
+        let arena = Bump::new();
+        let actual = parse_expr_with(&arena, "[\n    \"a\"\n    \"b\"\n]");
+
+        match actual {
+            Ok(List(elements)) => {
+                let strings: std::vec::Vec<_> = elements
+                    .iter()
+                    .map(|elem| elem.value.extract_spaces().item)
+                    .collect();
+
+                assert_eq!(strings, [Str(PlainLine("a")), Str(PlainLine("b"))]);
+            }
+            other => panic!("Expected a list of two strings, but got {other:?}"),
+        }
+    }
+
     #[test]
     fn empty_source_file() {
         assert_parsing_fails("", SyntaxError::Eof(Region::zero()));
     }
 
+    #[test]
+    fn whole_and_fractional_numbers() {
+        // Numbers keep their source text; only a decimal point (or an exponent)
+        // makes them fractional.
+        assert_parses_to("42", Num("42"));
+        assert_parses_to("42u8", Num("42u8"));
+        assert_parses_to("42.0", Float("42.0"));
+        assert_parses_to("42.", Float("42."));
+        assert_parses_to("1_000.5", Float("1_000.5"));
+        assert_parses_to("1e3", Float("1e3"));
+    }
+
+    #[test]
+    fn strict_number_literal_underscores() {
+        let arena = Bump::new();
+        let strict = |src| {
+            number_literal_strict()
+                .parse(&arena, State::new(src), 0)
+                .map(|_| ())
+                .map_err(|(_, fail)| fail)
+        };
+
+        assert_eq!(strict(b"1_234_567"), Ok(()));
+        assert_eq!(strict(b"0xFF_FFu8"), Ok(()));
+        assert_eq!(
+            strict(b"1_"),
+            Err(ENumber::MisplacedUnderscore(Region::new(
+                Position::new(1),
+                Position::new(2)
+            )))
+        );
+        assert_eq!(
+            strict(b"-1__2"),
+            Err(ENumber::MisplacedUnderscore(Region::new(
+                Position::new(2),
+                Position::new(3)
+            )))
+        );
+        assert_eq!(
+            strict(b"1_u8"),
+            Err(ENumber::MisplacedUnderscore(Region::new(
+                Position::new(1),
+                Position::new(2)
+            )))
+        );
+        assert_eq!(
+            strict(b"0xFF_u8"),
+            Err(ENumber::MisplacedUnderscore(Region::new(
+                Position::new(4),
+                Position::new(5)
+            )))
+        );
+
+        // The default parser still accepts them
+        assert_parses_to("1_", Num("1_"));
+        assert_parses_to("1__2", Num("1__2"));
+        assert_parses_to("1_u8", Num("1_u8"));
+    }
+
+    #[test]
+    fn integers_wider_than_i64() {
+        // The parser keeps the source text, and leaves range checks to canonicalization
+        for num in [
+            u64::MAX.to_string(),
+            i128::MAX.to_string(),
+            i128::MIN.to_string(),
+            u128::MAX.to_string(),
+        ] {
+            assert_parses_to(&num, Num(&num));
+        }
+    }
+
     #[quickcheck]
     fn all_i64_values_parse(num: i64) {
         assert_parses_to(num.to_string().as_str(), Num(num.to_string().as_str()));
@@ -319,12 +633,440 @@ mod test_parse {
         assert_parses_to(float_string.as_str(), Float(float_string.as_str()));
     }
 
+    // OPERATORS
+    #[test]
+    fn binop_precedences() {
+        let precedence = |op| binop_precedence(op).0;
+        let associativity = |op| binop_precedence(op).1;
+
+        assert!(precedence(BinOp::Star) > precedence(BinOp::Plus));
+        assert!(precedence(BinOp::Caret) > precedence(BinOp::Star));
+        assert!(precedence(BinOp::Plus) > precedence(BinOp::Pizza));
+        assert!(precedence(BinOp::Equals) > precedence(BinOp::And));
+        assert!(precedence(BinOp::And) > precedence(BinOp::Or));
+        assert_eq!(precedence(BinOp::Slash), precedence(BinOp::Star));
+
+        assert_eq!(associativity(BinOp::Caret), Associativity::RightAssociative);
+        assert_eq!(associativity(BinOp::Minus), Associativity::LeftAssociative);
+        assert_eq!(associativity(BinOp::Pizza), Associativity::LeftAssociative);
+        assert_eq!(associativity(BinOp::Equals), Associativity::NonAssociative);
+        assert_eq!(
+            associativity(BinOp::LessThan),
+            Associativity::NonAssociative
+        );
+    }
+
+    #[test]
+    fn negation_right_after_open_paren() {
+        // A minus right after `(` negates; it's never read as a subtraction
+        let arena = Bump::new();
+        let x = Loc::new(
+            2,
+            3,
+            Var {
+                module_name: "",
+                ident: "x",
+            },
+        );
+        let negated_x = UnaryOp(arena.alloc(x), Loc::new(1, 2, UnaryOp::Negate));
+        let minus_one = Loc::new(1, 3, Num("-1"));
+        let minus_two = Loc::new(5, 7, Num("-2"));
+        let items = [&minus_one, &minus_two];
+
+        assert_parses_to("(-1)", ParensAround(&Num("-1")));
+        assert_parses_to("(-x)", ParensAround(&negated_x));
+        assert_parses_to("(-1, -2)", Tuple(Collection::with_items(&items)));
+    }
+
+    fn var(name: &str) -> Expr<'_> {
+        Var {
+            module_name: "",
+            ident: name,
+        }
+    }
+
+    #[test]
+    fn not_parenthesized_expr() {
+        let arena = Bump::new();
+
+        match parse_expr_with(&arena, "!(a && b)") {
+            Ok(UnaryOp(operand, op)) => {
+                assert_eq!(op.value, UnaryOp::Not);
+                assert!(matches!(
+                    operand.value,
+                    ParensAround(BinOps([(_, and)], _)) if and.value == BinOp::And
+                ));
+            }
+            other => panic!("Expected !(a && b), but got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn unary_ops_bind_tighter_than_application() {
+        // `!f x` applies `!f` to `x`; write `!(f x)` to negate the call
+        let arena = Bump::new();
+        let x = Loc::new(3, 4, var("x"));
+        let args = [&x];
+
+        for (src, expected_op) in [("!f x", UnaryOp::Not), ("-f x", UnaryOp::Negate)] {
+            let f = arena.alloc(Loc::new(1, 2, var("f")));
+            let op_f = Loc::new(0, 2, UnaryOp(f, Loc::new(0, 1, expected_op)));
+
+            assert_parses_to(src, Apply(arena.alloc(op_f), &args, CalledVia::Space));
+        }
+    }
+
+    #[test]
+    fn unary_ops_bind_tighter_than_binops() {
+        // `!x == y` compares `!x` with `y`
+        let arena = Bump::new();
+        let x = Loc::new(1, 2, var("x"));
+        let not_x = Loc::new(0, 2, UnaryOp(arena.alloc(x), Loc::new(0, 1, UnaryOp::Not)));
+        let lefts = [(not_x, Loc::new(3, 5, BinOp::Equals))];
+        let y = Loc::new(6, 7, var("y"));
+
+        assert_parses_to("!x == y", BinOps(&lefts, &y));
+    }
+
+    // RECORD ACCESS
+    fn access_chain_fields<'a>(mut expr: &'a Expr<'a>) -> (std::vec::Vec<&'a str>, &'a Expr<'a>) {
+        let mut fields = std::vec::Vec::new();
+
+        while let RecordAccess(inner, field) = expr {
+            fields.push(*field);
+            expr = inner;
+        }
+
+        fields.reverse();
+
+        (fields, expr)
+    }
+
+    #[test]
+    fn deeply_nested_record_access() {
+        // A long but fixed-size chain; every access must end up nested in source order
+        let fields: std::vec::Vec<String> = (0..1000).map(|i| format!("f{i}")).collect();
+        let chain = fields.join(".");
+        let rec = var("rec");
+
+        for (src, expected_innermost) in [
+            (format!("rec.{chain}"), rec),
+            (format!("(rec).{chain}"), ParensAround(&rec)),
+        ] {
+            let arena = Bump::new();
+            let expr = parse_expr_with(&arena, arena.alloc(src)).unwrap();
+            let (actual_fields, innermost) = access_chain_fields(arena.alloc(expr));
+
+            assert_eq!(actual_fields, fields);
+            assert_eq!(innermost, &expected_innermost);
+        }
+
+        let arena = Bump::new();
+        let src = format!("{{ x: 1 }}.{chain}");
+        let expr = parse_expr_with(&arena, arena.alloc(src)).unwrap();
+        let (actual_fields, innermost) = access_chain_fields(arena.alloc(expr));
+
+        assert_eq!(actual_fields, fields);
+        assert!(matches!(innermost, Record(fields) if fields.len() == 1));
+    }
+
+    #[test]
+    fn pipe_into_accessor_function() {
+        let arena = Bump::new();
+        let x = Loc::new(
+            0,
+            1,
+            Var {
+                module_name: "",
+                ident: "x",
+            },
+        );
+        let firsts = [(x, Loc::new(2, 4, BinOp::Pizza))];
+        let accessor = Loc::new(5, 11, AccessorFunction(Accessor::RecordField("field")));
+        let expected = BinOps(&firsts, arena.alloc(accessor));
+
+        assert_parses_to("x |> .field", expected);
+    }
+
+    #[test]
+    fn pipes_into_map_with_accessor_functions() {
+        let arena = Bump::new();
+        let map_field = |start: u32, field: &'static str| {
+            let end = start + 10 + field.len() as u32;
+            let map = Loc::new(
+                start,
+                start + 8,
+                Var {
+                    module_name: "List",
+                    ident: "map",
+                },
+            );
+            let accessor = Loc::new(
+                start + 9,
+                end,
+                AccessorFunction(Accessor::RecordField(field)),
+            );
+            let args = &*arena.alloc([&*arena.alloc(accessor)]);
+
+            Loc::new(start, end, Apply(arena.alloc(map), args, CalledVia::Space))
+        };
+        let records = Loc::new(
+            0,
+            7,
+            Var {
+                module_name: "",
+                ident: "records",
+            },
+        );
+        let firsts = [
+            (records, Loc::new(8, 10, BinOp::Pizza)),
+            (map_field(11, "user"), Loc::new(26, 28, BinOp::Pizza)),
+        ];
+        let expected = BinOps(&firsts, arena.alloc(map_field(29, "name")));
+
+        assert_parses_to("records |> List.map .user |> List.map .name", expected);
+    }
+
+    // PATTERNS
+
+    #[test]
+    fn parenthesized_when_patterns() {
+        let arena = Bump::new();
+        let src = indoc!(
+            r"
+            when opt is
+                (Just x) -> x
+                ((a, b)) -> a
+                _ -> 0
+            "
+        );
+        let branches = match parse_expr_with(&arena, src.trim()) {
+            Ok(When(_, branches)) => branches,
+            other => panic!("Expected a when, but got {other:?}"),
+        };
+        let first_pattern = |index: usize| {
+            let loc_pattern = branches[index].patterns[0];
+
+            (loc_pattern.region, loc_pattern.value.extract_spaces().item)
+        };
+
+        // The parens are dropped, so the region is just that of the pattern inside them
+        let (region, pattern) = first_pattern(0);
+        assert_eq!(region, Region::new(Position::new(17), Position::new(23)));
+        assert!(matches!(
+            pattern,
+            ast::Pattern::Apply(
+                Loc {
+                    value: ast::Pattern::Tag("Just"),
+                    ..
+                },
+                [Loc {
+                    value: ast::Pattern::Identifier { ident: "x" },
+                    ..
+                }]
+            )
+        ));
+
+        // A tuple's own parens are part of its region, but the extra ones aren't
+        let (region, pattern) = first_pattern(1);
+        assert_eq!(region, Region::new(Position::new(35), Position::new(41)));
+        assert!(matches!(pattern, ast::Pattern::Tuple(items) if items.len() == 2));
+    }
+
+    #[test]
+    fn when_over_tuple() {
+        let arena = Bump::new();
+        let src = indoc!(
+            r"
+            when pair is
+                (0, _) -> Left
+                (_, 0) -> Right
+                _ -> Neither
+            "
+        );
+        let branches = match parse_expr_with(&arena, src.trim()) {
+            Ok(When(_, branches)) => branches,
+            other => panic!("Expected a when, but got {other:?}"),
+        };
+        let tuple_items =
+            |index: usize| match branches[index].patterns[0].value.extract_spaces().item {
+                ast::Pattern::Tuple(items) => items
+                    .iter()
+                    .map(|item| item.value.extract_spaces().item)
+                    .collect::<std::vec::Vec<_>>(),
+                other => panic!("Expected a tuple pattern, but got {other:?}"),
+            };
+
+        assert_eq!(
+            tuple_items(0),
+            [ast::Pattern::NumLiteral("0"), ast::Pattern::Underscore("")]
+        );
+        assert_eq!(
+            tuple_items(1),
+            [ast::Pattern::Underscore(""), ast::Pattern::NumLiteral("0")]
+        );
+    }
+
+    #[test]
+    fn when_with_tuple_condition() {
+        let arena = Bump::new();
+        let src = indoc!(
+            r"
+            when (a, b) is
+                (1, 2) -> a
+                _ -> b
+            "
+        );
+
+        match parse_expr_with(&arena, src.trim()) {
+            Ok(When(condition, branches)) => {
+                assert!(matches!(condition.value, Tuple(items) if items.len() == 2));
+                assert!(matches!(
+                    branches[0].patterns[0].value.extract_spaces().item,
+                    ast::Pattern::Tuple(items) if items.len() == 2
+                ));
+            }
+            other => panic!("Expected a when, but got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn when_branch_bodies_starting_with_unary_operators() {
+        // The `-` follows the whitespace after `->`, so it's a unary negation
+        let arena = Bump::new();
+
+        for (src, expected_op) in [
+            ("when a is\n    _ -> -x", Some(UnaryOp::Negate)),
+            ("when a is\n    _ -> !flag", Some(UnaryOp::Not)),
+            ("when a is\n    _ -> -1", None),
+        ] {
+            let branch = match parse_expr_with(&arena, src) {
+                Ok(When(_, [branch])) => branch,
+                other => panic!("Expected a when with one branch, but got {other:?}"),
+            };
+
+            match (branch.value.value, expected_op) {
+                (UnaryOp(_, op), Some(expected_op)) => assert_eq!(op.value, expected_op, "{src:?}"),
+                (Num("-1"), None) => {}
+                (other, _) => panic!("Unexpected branch body {other:?} in {src:?}"),
+            }
+        }
+    }
+
+    // BOOLEANS
+    // `True` and `False` are ordinary tags, and `Bool.true` an ordinary qualified lookup;
+    // none of them are keywords.
+
+    #[test]
+    fn bool_tags() {
+        assert_parses_to("True", Tag("True"));
+        assert_parses_to("False", Tag("False"));
+    }
+
+    #[test]
+    fn if_with_bool_tag_condition() {
+        let a = Var {
+            module_name: "",
+            ident: "a",
+        };
+        let b = Loc::new(
+            20,
+            21,
+            Var {
+                module_name: "",
+                ident: "b",
+            },
+        );
+        let if_thens = [(Loc::new(3, 7, Tag("True")), Loc::new(13, 14, a))];
+
+        assert_parses_to(
+            "if True then a else b",
+            If {
+                if_thens: &if_thens,
+                final_else: &b,
+                indented_else: false,
+            },
+        );
+    }
+
+    #[test]
+    fn if_without_condition() {
+        // The error points right after the `if`, where the condition should have started
+        let arena = Bump::new();
+
+        assert_eq!(
+            Err(SyntaxError::Expr(
+                EExpr::If(
+                    EIf::Condition(
+                        arena.alloc(EExpr::Start(Position::new(3))),
+                        Position::new(3)
+                    ),
+                    Position::new(0)
+                ),
+                Position::new(0)
+            )),
+            parse_expr_with(&arena, "if then x else y")
+        );
+    }
+
+    #[test]
+    fn def_with_bool_tag_body() {
+        let arena = Bump::new();
+        let defs = parse_module_defs(&arena, State::new(b"x = False\n"), ast::Defs::default())
+            .expect("Failed to parse!");
+
+        match defs.value_defs.first() {
+            Some(ast::ValueDef::Body(pattern, body)) => {
+                assert_eq!(pattern.value, ast::Pattern::Identifier { ident: "x" });
+                assert_eq!(body.value, Tag("False"));
+            }
+            other => panic!("Expected a def, but got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn qualified_bool_value() {
+        assert_parses_to(
+            "Bool.true",
+            Var {
+                module_name: "Bool",
+                ident: "true",
+            },
+        );
+    }
+
     // SINGLE QUOTE LITERAL
     #[test]
     fn single_quote() {
         assert_parses_to("'b'", Expr::SingleQuote("b"));
     }
 
+    // EMPTY COLLECTIONS
+    #[test]
+    fn empty_list_and_record() {
+        let arena = Bump::new();
+
+        for src in ["[]", "[ ]", "[  ]"] {
+            let loc_expr = parse_loc_with(&arena, src).unwrap();
+
+            assert_eq!(loc_expr.value, List(Collection::empty()));
+            assert_eq!(
+                loc_expr.region,
+                Region::new(Position::new(0), Position::new(src.len() as u32))
+            );
+        }
+
+        for src in ["{}", "{ }", "{  }"] {
+            let loc_expr = parse_loc_with(&arena, src).unwrap();
+
+            assert_eq!(loc_expr.value, Record(Collection::empty()));
+            assert_eq!(
+                loc_expr.region,
+                Region::new(Position::new(0), Position::new(src.len() as u32))
+            );
+        }
+    }
+
     #[test]
     fn repro_keyword_bug() {
         // Reproducing this bug requires a bizarre set of things to all be true:
@@ -354,6 +1096,162 @@ mod test_parse {
         assert_eq!(occurrences, 2);
     }
 
+    fn first_annotation<'a>(arena: &'a Bump, src: &'a str) -> ast::TypeAnnotation<'a> {
+        let defs = parse_module_defs(arena, State::new(src.as_bytes()), ast::Defs::default())
+            .expect("Failed to parse!");
+
+        match defs.value_defs.first() {
+            Some(ast::ValueDef::Annotation(_, loc_ann)) => loc_ann.value,
+            other => panic!("Expected an annotation, but got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn qualified_type_application() {
+        let arena = Bump::new();
+
+        match first_annotation(&arena, "x : Json.Decoder a\n") {
+            ast::TypeAnnotation::Apply("Json", "Decoder", [arg]) => {
+                assert_eq!(arg.value, ast::TypeAnnotation::BoundVariable("a"));
+            }
+            other => panic!("Expected Json.Decoder a, but got {other:?}"),
+        }
+
+        match first_annotation(&arena, "x : A.B.C x\n") {
+            ast::TypeAnnotation::Apply("A.B", "C", [arg]) => {
+                assert_eq!(arg.value, ast::TypeAnnotation::BoundVariable("x"));
+            }
+            other => panic!("Expected A.B.C x, but got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn lowercase_segment_in_qualified_type() {
+        let arena = Bump::new();
+
+        assert_eq!(
+            first_annotation(&arena, "x : Json.decoder a\n"),
+            ast::TypeAnnotation::Malformed("Json.decoder")
+        );
+    }
+
+    #[test]
+    fn function_type_without_effect() {
+        let arena = Bump::new();
+
+        match first_annotation(&arena, "f : Str -> {}\n") {
+            ast::TypeAnnotation::Function([arg], ret, None) => {
+                assert_eq!(arg.value, ast::TypeAnnotation::Apply("", "Str", &[]));
+                assert!(matches!(ret.value, ast::TypeAnnotation::Record { .. }));
+            }
+            other => panic!("Expected Str -> {{}}, but got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn function_type_with_effect() {
+        let arena = Bump::new();
+
+        match first_annotation(&arena, "f : Str -> {} | Effect\n") {
+            ast::TypeAnnotation::Function([arg], ret, Some(effect)) => {
+                assert_eq!(arg.value, ast::TypeAnnotation::Apply("", "Str", &[]));
+                assert!(matches!(ret.value, ast::TypeAnnotation::Record { .. }));
+                assert_eq!(effect.value, ast::TypeAnnotation::Apply("", "Effect", &[]));
+                assert_eq!(
+                    effect.region,
+                    Region::new(Position::new(16), Position::new(22))
+                );
+            }
+            other => panic!("Expected Str -> {{}} | Effect, but got {other:?}"),
+        }
+
+        // `|` must be followed by an effect
+        assert!(parse_module_defs(
+            &arena,
+            State::new(b"f : Str -> {} |\n"),
+            ast::Defs::default()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn annotation_colon_on_next_line() {
+        // The `:` can't start an expression, so it may continue the def on the next line,
+        // whether it's indented or aligned with the name
+        let arena = Bump::new();
+
+        for src in ["x : Str\n", "x\n    : Str\n", "x\n: Str\n"] {
+            assert_eq!(
+                first_annotation(&arena, src).extract_spaces().item,
+                ast::TypeAnnotation::Apply("", "Str", &[]),
+                "{src:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn consecutive_type_aliases() {
+        let arena = Bump::new();
+        let src = indoc!(
+            r"
+                Foo : A
+                Bar : B
+                Baz a : List a
+            "
+        );
+        let defs = parse_module_defs(&arena, State::new(src.as_bytes()), ast::Defs::default())
+            .expect("Failed to parse!");
+
+        let names: std::vec::Vec<_> = defs
+            .defs()
+            .map(|def| match def {
+                Ok(ast::TypeDef::Alias { header, .. }) => header.name.value,
+                other => panic!("Expected an alias, but got {other:?}"),
+            })
+            .collect();
+
+        assert_eq!(names, ["Foo", "Bar", "Baz"]);
+    }
+
+    fn alias_var_names<'a>(arena: &'a Bump, src: &'a str) -> std::vec::Vec<&'a str> {
+        let defs = parse_module_defs(arena, State::new(src.as_bytes()), ast::Defs::default())
+            .expect("Failed to parse!");
+
+        match defs.type_defs.first() {
+            Some(ast::TypeDef::Alias { header, .. }) => header
+                .vars
+                .iter()
+                .map(|var| match var.value.extract_spaces().item {
+                    ast::Pattern::Identifier { ident } => ident,
+                    other => panic!("Expected a type variable, but got {other:?}"),
+                })
+                .collect(),
+            other => panic!("Expected an alias, but got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn alias_with_two_type_vars() {
+        let arena = Bump::new();
+
+        assert_eq!(
+            alias_var_names(&arena, "Map k v : List (k, v)\n"),
+            ["k", "v"]
+        );
+    }
+
+    #[test]
+    fn alias_with_phantom_type_var() {
+        // Whether every var is used is checked during canonicalization, not parsing
+        let arena = Bump::new();
+
+        assert_eq!(alias_var_names(&arena, "Id a : U64\n"), ["a"]);
+        assert_eq!(
+            alias_var_names(&arena, "Tagged tag a : List a\n"),
+            ["tag", "a"]
+        );
+    }
+
     #[test]
     fn outdenting_newline_after_else() {
         let arena = &Bump::new();
@@ -381,6 +1279,322 @@ mod test_parse {
         }
     }
 
+    #[test]
+    fn parse_module_without_header() {
+        let arena = Bump::new();
+        let src = indoc!(
+            r"
+            x = 1
+
+            y = x + 1
+            "
+        );
+
+        let module = parse_module(&arena, src).unwrap();
+
+        assert!(module.header.is_none());
+        assert_eq!(module.defs.len(), 2);
+    }
+
+    #[test]
+    fn parse_module_keeps_blank_lines_between_defs() {
+        fn newlines_before_second_def(src: &str) -> usize {
+            let arena = Bump::new();
+            let defs = parse_module(&arena, src).unwrap().defs;
+
+            defs.spaces[defs.space_before[1].indices()]
+                .iter()
+                .filter(|space| matches!(space, CommentOrNewline::Newline))
+                .count()
+        }
+
+        // the newline ending `a = 1`, plus one per blank line
+        assert_eq!(newlines_before_second_def("a = 1\n\nb = 2\n"), 2);
+        assert_eq!(newlines_before_second_def("a = 1\n\n\nb = 2\n"), 3);
+    }
+
+    #[test]
+    fn parse_module_ending_in_annotation() {
+        // Unlike in an expression, a module's defs don't need a final expression
+        let arena = Bump::new();
+        let src = indoc!(
+            r"
+            x = 1
+
+            foo : I64
+            "
+        );
+
+        let defs = parse_module(&arena, src).unwrap().defs;
+
+        assert_eq!(defs.len(), 2);
+        assert!(matches!(
+            defs.value_defs.last(),
+            Some(ast::ValueDef::Annotation(
+                Loc {
+                    value: ast::Pattern::Identifier { ident: "foo" },
+                    ..
+                },
+                _
+            ))
+        ));
+    }
+
+    #[test]
+    fn dangling_annotation_in_expr() {
+        let arena = Bump::new();
+        let src = indoc!(
+            r"
+            x =
+                foo : I64
+
+            x
+            "
+        );
+
+        assert_eq!(
+            Err(SyntaxError::Expr(
+                EExpr::DefMissingFinalExpr(Position::new(17)),
+                Position::new(0)
+            )),
+            parse_expr_with(&arena, src.trim())
+        );
+    }
+
+    #[test]
+    fn parse_module_with_header() {
+        let arena = Bump::new();
+        let src = indoc!(
+            r"
+            module [x]
+
+            import Foo
+
+            x = Foo.y
+            "
+        );
+
+        let module = parse_module(&arena, src).unwrap();
+
+        assert!(matches!(
+            module.header.map(|header| header.item),
+            Some(ast::Header::Module(_))
+        ));
+        // the import is a def alongside `x`
+        assert_eq!(module.defs.len(), 2);
+    }
+
+    #[test]
+    fn import_exposing_with_alias() {
+        let arena = Bump::new();
+        let src = "import Json exposing [decode, map as jsonMap, Decoder as JsonDecoder]\n";
+
+        let defs =
+            parse_module_defs(&arena, State::new(src.as_bytes()), ast::Defs::default()).unwrap();
+
+        let exposed = match defs.value_defs.first() {
+            Some(ast::ValueDef::ModuleImport(import)) => import.exposed.unwrap().item,
+            other => panic!("Expected an import, got {other:?}"),
+        };
+
+        let names: std::vec::Vec<_> = exposed
+            .iter()
+            .map(|loc_name| {
+                let entry = loc_name.value.item();
+
+                (
+                    entry.name.as_str(),
+                    entry.alias.map(|alias| <&str>::from(alias.item.value)),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            names,
+            [
+                ("decode", None),
+                ("map", Some("jsonMap")),
+                ("Decoder", Some("JsonDecoder")),
+            ]
+        );
+    }
+
+    #[test]
+    fn import_exposing_alias_with_mismatched_case() {
+        let arena = Bump::new();
+
+        for src in [
+            "import Json exposing [map as JsonMap]\n",
+            "import Json exposing [Decoder as decoder]\n",
+        ] {
+            let result =
+                parse_module_defs(&arena, State::new(src.as_bytes()), ast::Defs::default());
+
+            assert!(
+                matches!(
+                    result,
+                    Err(SyntaxError::Expr(
+                        EExpr::Import(EImport::ExposedAliasCase(_), _),
+                        _
+                    ))
+                ),
+                "{src:?} gave {result:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_module_with_malformed_header() {
+        let arena = Bump::new();
+
+        assert!(matches!(
+            parse_module(&arena, "module [x\n\nx = 1"),
+            Err(SyntaxError::Header(_))
+        ));
+    }
+
+    #[test]
+    fn parse_expr_partial_stops_after_expr() {
+        let arena = Bump::new();
+        let src = "1 + 2 extra";
+
+        let (result, pos) = parse_expr_partial(&arena, State::new(src.as_bytes()));
+
+        assert_eq!(
+            result.unwrap().region,
+            Region::new(Position::new(0), Position::new(5))
+        );
+        // just past the `2`, since a number can't be applied to `extra`
+        assert_eq!(pos, Position::new(5));
+    }
+
+    #[test]
+    fn parse_expr_partial_reports_where_it_failed() {
+        let arena = Bump::new();
+        let src = "1 + )";
+
+        let (result, pos) = parse_expr_partial(&arena, State::new(src.as_bytes()));
+
+        assert!(matches!(result, Err(EExpr::TrailingOperator(_))));
+        assert_eq!(pos, Position::new(4));
+    }
+
+    #[test]
+    fn peek_never_advances() {
+        let arena = Bump::new();
+        let state = State::new("hello, world".as_bytes());
+
+        let parser = peek(and(word("hello", EExpr::Start), word(",", EExpr::Start)));
+        let (progress, _, after) = parser.parse(&arena, state.clone(), 0).unwrap();
+        assert_eq!(progress, Progress::NoProgress);
+        assert_eq!(after.pos(), Position::zero());
+
+        // the inner parser consumes `hello` before failing, but peek still reports no progress
+        let parser = peek(and(word("hello", EExpr::Start), word("!", EExpr::Start)));
+        let (progress, err) = parser.parse(&arena, state, 0).unwrap_err();
+        assert_eq!(progress, Progress::NoProgress);
+        assert_eq!(err, EExpr::Start(Position::new(5)));
+    }
+
+    #[test]
+    fn optional_with_default_present_and_absent() {
+        let arena = Bump::new();
+        let parser = optional_with_default(map(word("hello", EExpr::Start), |()| 1), 0);
+
+        let state = State::new("hello, world".as_bytes());
+        let (progress, output, after) = parser.parse(&arena, state, 0).unwrap();
+        assert_eq!(progress, Progress::MadeProgress);
+        assert_eq!(output, 1);
+        assert_eq!(after.pos(), Position::new(5));
+
+        let state = State::new("bye, world".as_bytes());
+        let (progress, output, after) = parser.parse(&arena, state, 0).unwrap();
+        assert_eq!(progress, Progress::NoProgress);
+        assert_eq!(output, 0);
+        assert_eq!(after.pos(), Position::zero());
+
+        // an error after making progress is not replaced by the default
+        let parser = optional_with_default(
+            map(
+                and(word("hello", EExpr::Start), word("!", EExpr::End)),
+                |_| 1,
+            ),
+            0,
+        );
+        let state = State::new("hello, world".as_bytes());
+        let (progress, err) = parser.parse(&arena, state, 0).unwrap_err();
+        assert_eq!(progress, Progress::MadeProgress);
+        assert_eq!(err, EExpr::End(Position::new(5)));
+    }
+
+    #[test]
+    fn and_then_keeps_progress() {
+        let arena = Bump::new();
+        let state = State::new("hello, world".as_bytes());
+
+        let parser = and_then(word("hello", EExpr::Start), |()| Ok(5));
+        let (progress, output, after) = parser.parse(&arena, state.clone(), 0).unwrap();
+        assert_eq!(progress, Progress::MadeProgress);
+        assert_eq!(output, 5);
+        assert_eq!(after.pos(), Position::new(5));
+
+        let parser = and_then(succeed(()), |()| Err::<(), _>(EExpr::End(Position::zero())));
+        let (progress, _) = parser.parse(&arena, state.clone(), 0).unwrap_err();
+        assert_eq!(progress, Progress::NoProgress);
+
+        // when the inner parser fails, the function is never called
+        let parser = and_then(word("bye", EExpr::Start), |()| -> Result<(), _> {
+            unreachable!()
+        });
+        let (progress, err) = parser.parse(&arena, state, 0).unwrap_err();
+        assert_eq!(progress, Progress::NoProgress);
+        assert_eq!(err, EExpr::Start(Position::zero()));
+    }
+
+    #[test]
+    fn and_then_error_position() {
+        // the error points at the rejected value, not where parsing stopped
+        let arena = Bump::new();
+        let state = State::new("hello, world".as_bytes());
+
+        let parser = and_then(loc(word("hello", EExpr::Start)), |loc_word| {
+            Err::<(), _>(EExpr::End(loc_word.region.start()))
+        });
+        let (progress, err) = parser.parse(&arena, state, 0).unwrap_err();
+        assert_eq!(progress, Progress::MadeProgress);
+        assert_eq!(err, EExpr::End(Position::zero()));
+    }
+
+    #[test]
+    fn when_branch_body_with_defs() {
+        let arena = Bump::new();
+        let src = indoc!(
+            r"
+            when m is
+                Just x ->
+                    y = x + 1
+                    y * 2
+
+                Nothing -> 0
+            "
+        );
+
+        let branches = match parse_expr_with(&arena, src.trim()) {
+            Ok(When(_, branches)) => branches,
+            other => panic!("Expected a when, but got {:?}", other),
+        };
+
+        assert_eq!(branches.len(), 2);
+
+        match branches[0].value.value.extract_spaces().item {
+            Defs(defs, final_expr) => {
+                assert_eq!(defs.len(), 1);
+                assert!(matches!(final_expr.extract_spaces().item, BinOps(..)));
+            }
+            other => panic!("Expected the branch body to be defs, but got {:?}", other),
+        }
+    }
+
     #[test]
     fn parse_expr_size() {
         assert_eq!(std::mem::size_of::<roc_parse::ast::Expr>(), 40);
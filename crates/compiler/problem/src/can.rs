@@ -117,6 +117,9 @@ pub enum Problem {
     InvalidInterpolation(Region),
     InvalidHexadecimal(Region),
     InvalidUnicodeCodePt(Region),
+    UnicodeSurrogateCodePt(Region),
+    /// A string literal with a tab typed directly into it, rather than written as `\t`
+    RawTabInString(Region),
     NestedDatatype {
         alias: Symbol,
         def_region: Region,
@@ -286,6 +289,8 @@ impl Problem {
             Problem::InvalidInterpolation(_) => RuntimeError,
             Problem::InvalidHexadecimal(_) => RuntimeError,
             Problem::InvalidUnicodeCodePt(_) => RuntimeError,
+            Problem::UnicodeSurrogateCodePt(_) => RuntimeError,
+            Problem::RawTabInString(_) => Warning,
             Problem::NestedDatatype { .. } => RuntimeError,
             Problem::InvalidExtensionType { .. } => RuntimeError,
             Problem::AbilityHasTypeVariables { .. } => RuntimeError,
@@ -424,6 +429,7 @@ impl Problem {
             | Problem::RuntimeError(RuntimeError::InvalidInterpolation(region))
             | Problem::RuntimeError(RuntimeError::InvalidHexadecimal(region))
             | Problem::RuntimeError(RuntimeError::InvalidUnicodeCodePt(region))
+            | Problem::RuntimeError(RuntimeError::UnicodeSurrogateCodePt(region))
             | Problem::RuntimeError(RuntimeError::EmptySingleQuote(region))
             | Problem::RuntimeError(RuntimeError::MultipleCharsInSingleQuote(region))
             | Problem::RuntimeError(RuntimeError::DegenerateBranch(region))
@@ -433,11 +439,14 @@ impl Problem {
                 record: _,
                 field: region,
             })
+            | Problem::RuntimeError(RuntimeError::UnsupportedSpread(region))
             | Problem::RuntimeError(RuntimeError::ReadIngestedFileError { region, .. })
             | Problem::InvalidAliasRigid { region, .. }
             | Problem::InvalidInterpolation(region)
             | Problem::InvalidHexadecimal(region)
             | Problem::InvalidUnicodeCodePt(region)
+            | Problem::UnicodeSurrogateCodePt(region)
+            | Problem::RawTabInString(region)
             | Problem::NestedDatatype {
                 def_region: region, ..
             }
@@ -665,6 +674,7 @@ pub enum RuntimeError {
     InvalidInterpolation(Region),
     InvalidHexadecimal(Region),
     InvalidUnicodeCodePt(Region),
+    UnicodeSurrogateCodePt(Region),
 
     /// When the author specifies a type annotation but no implementation
     NoImplementationNamed {
@@ -691,6 +701,9 @@ pub enum RuntimeError {
         field: Region,
     },
 
+    /// A spread element like `..rest` in a list or tuple, which is parsed but not yet supported
+    UnsupportedSpread(Region),
+
     MalformedSuffixed(Region),
 }
 
@@ -740,8 +753,10 @@ impl RuntimeError {
                 record: _,
                 field: region,
             }
+            | RuntimeError::UnsupportedSpread(region)
             | RuntimeError::ReadIngestedFileError { region, .. } => *region,
-            RuntimeError::InvalidUnicodeCodePt(region) => *region,
+            RuntimeError::InvalidUnicodeCodePt(region)
+            | RuntimeError::UnicodeSurrogateCodePt(region) => *region,
             RuntimeError::UnresolvedTypeVar | RuntimeError::ErroneousType => Region::zero(),
             RuntimeError::LookupNotInScope { loc_name, .. } => loc_name.region,
             RuntimeError::OpaqueNotDefined { usage, .. } => usage.region,
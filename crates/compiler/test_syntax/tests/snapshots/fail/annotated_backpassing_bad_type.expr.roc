@@ -0,0 +1,2 @@
+x : List ( <- f
+x
\ No newline at end of file
@@ -0,0 +1 @@
+g as
\ No newline at end of file
@@ -0,0 +1 @@
+f is
\ No newline at end of file
@@ -0,0 +1 @@
+h then
\ No newline at end of file
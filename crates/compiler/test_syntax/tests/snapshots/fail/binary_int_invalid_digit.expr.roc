@@ -0,0 +1 @@
+0b12
\ No newline at end of file
@@ -0,0 +1 @@
+If cond then a else b
\ No newline at end of file
@@ -0,0 +1,2 @@
+x as y
+x
\ No newline at end of file
@@ -0,0 +1,2 @@
+x = +5
+x
\ No newline at end of file
@@ -0,0 +1,3 @@
+Red | Green = color
+
+1
\ No newline at end of file
@@ -0,0 +1,4 @@
+expect
+1 == 1
+
+x
\ No newline at end of file
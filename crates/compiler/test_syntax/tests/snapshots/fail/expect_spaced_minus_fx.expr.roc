@@ -0,0 +1 @@
+expect - fx
\ No newline at end of file
@@ -0,0 +1,2 @@
+x = 1
+expect x == 1
\ No newline at end of file
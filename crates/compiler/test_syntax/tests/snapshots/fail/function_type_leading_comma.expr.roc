@@ -0,0 +1,4 @@
+f : , a -> b
+f = \_ -> 1
+
+f
\ No newline at end of file
@@ -0,0 +1,2 @@
+f : Str -> {} |
+f
\ No newline at end of file
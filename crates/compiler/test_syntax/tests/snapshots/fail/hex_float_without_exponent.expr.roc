@@ -0,0 +1 @@
+0x1.8
\ No newline at end of file
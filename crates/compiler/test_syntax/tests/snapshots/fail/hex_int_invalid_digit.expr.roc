@@ -0,0 +1 @@
+0xZZ
\ No newline at end of file
@@ -0,0 +1 @@
+if then x else y
\ No newline at end of file
@@ -0,0 +1 @@
+f if c then a else b
\ No newline at end of file
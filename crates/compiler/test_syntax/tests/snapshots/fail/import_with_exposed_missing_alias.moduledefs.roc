@@ -0,0 +1 @@
+import Json exposing [map as]
@@ -0,0 +1 @@
+import Json exposing [Decoder as decoder]
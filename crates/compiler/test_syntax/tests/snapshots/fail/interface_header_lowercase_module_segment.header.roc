@@ -0,0 +1 @@
+interface Foo.bar exposes [a] imports []
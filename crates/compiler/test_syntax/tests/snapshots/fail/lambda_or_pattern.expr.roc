@@ -0,0 +1 @@
+\Red | Green -> 1
\ No newline at end of file
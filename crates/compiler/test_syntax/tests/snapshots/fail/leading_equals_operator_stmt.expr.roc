@@ -0,0 +1,3 @@
+x = 1
+
+== x
\ No newline at end of file
@@ -0,0 +1,7 @@
+nums = [
+    1,
+    2
+    3,
+]
+
+nums
\ No newline at end of file
@@ -0,0 +1 @@
+[..f x]
\ No newline at end of file
@@ -0,0 +1 @@
+[.. rest]
\ No newline at end of file
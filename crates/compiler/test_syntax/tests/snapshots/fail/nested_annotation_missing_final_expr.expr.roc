@@ -0,0 +1,4 @@
+f =
+    g : I64
+
+f
\ No newline at end of file
@@ -0,0 +1,5 @@
+f = \x ->
+    \y ->
+    x + y
+
+f
\ No newline at end of file
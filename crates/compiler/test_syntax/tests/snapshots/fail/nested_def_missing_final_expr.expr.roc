@@ -0,0 +1,3 @@
+f =
+    a = 1
+f
\ No newline at end of file
@@ -0,0 +1,4 @@
+f =
+    a = 1
+    b = 2
+f
\ No newline at end of file
@@ -0,0 +1 @@
+007
\ No newline at end of file
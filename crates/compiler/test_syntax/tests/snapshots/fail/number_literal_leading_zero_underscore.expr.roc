@@ -0,0 +1 @@
+0_7
\ No newline at end of file
@@ -0,0 +1 @@
+0o99
\ No newline at end of file
@@ -0,0 +1,5 @@
+result =
+    list
+|> f
+
+result
\ No newline at end of file
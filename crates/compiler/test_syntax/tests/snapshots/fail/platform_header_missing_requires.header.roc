@@ -0,0 +1,5 @@
+platform "rtfeldman/blah"
+    exposes []
+    packages {}
+    imports []
+    provides []
@@ -0,0 +1,6 @@
+platform "rtfeldman/blah"
+    requires {} { main : {} }
+    packages {}
+    exposes []
+    imports []
+    provides []
@@ -0,0 +1,2 @@
+{ x: f y } : T
+1
\ No newline at end of file
@@ -0,0 +1,2 @@
+f : { name : }
+f
\ No newline at end of file
@@ -0,0 +1,2 @@
+f : { age ? }
+f
\ No newline at end of file
@@ -0,0 +1 @@
+{ r && x: 1 }
\ No newline at end of file
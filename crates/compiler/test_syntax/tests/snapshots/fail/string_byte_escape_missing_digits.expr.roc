@@ -0,0 +1 @@
+"\xZ"
\ No newline at end of file
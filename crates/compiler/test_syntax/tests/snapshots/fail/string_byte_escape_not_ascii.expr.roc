@@ -0,0 +1 @@
+"\xFF"
\ No newline at end of file
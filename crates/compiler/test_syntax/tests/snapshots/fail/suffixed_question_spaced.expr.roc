@@ -0,0 +1 @@
+rec.name ? x
\ No newline at end of file
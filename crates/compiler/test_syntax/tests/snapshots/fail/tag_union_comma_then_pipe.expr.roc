@@ -0,0 +1,2 @@
+x : [ A | B ]
+x
\ No newline at end of file
@@ -0,0 +1,2 @@
+x : [ | A | ]
+x
\ No newline at end of file
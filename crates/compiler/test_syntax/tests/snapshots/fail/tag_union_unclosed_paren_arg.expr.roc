@@ -0,0 +1,3 @@
+f : [Node (Tree a]
+
+f
\ No newline at end of file
@@ -0,0 +1 @@
+"\u{41}"
\ No newline at end of file
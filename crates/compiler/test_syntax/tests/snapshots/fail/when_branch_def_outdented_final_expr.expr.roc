@@ -0,0 +1,5 @@
+when m is
+    Just x ->
+        y = x + 1
+    y * 2
+    Nothing -> 0
\ No newline at end of file
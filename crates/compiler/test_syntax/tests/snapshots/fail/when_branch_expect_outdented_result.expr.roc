@@ -0,0 +1,4 @@
+when x is
+    Ok v ->
+        expect v > 0
+    v
\ No newline at end of file
@@ -0,0 +1,7 @@
+x =
+    when
+        longExpr
+            |> transform
+  is
+        _ -> 1
+x
\ No newline at end of file
@@ -0,0 +1,2 @@
+f x when x is
+    _ -> 1
\ No newline at end of file
@@ -0,0 +1,3 @@
+f = when x is
+
+f
\ No newline at end of file
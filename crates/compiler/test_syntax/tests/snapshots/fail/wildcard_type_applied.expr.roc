@@ -0,0 +1,2 @@
+f : * a
+f
\ No newline at end of file
@@ -0,0 +1,4 @@
+x : Json.decoder
+x = 1
+
+x
\ No newline at end of file
@@ -0,0 +1,4 @@
+x : Json.decoder a
+x = 1
+
+x
\ No newline at end of file
@@ -0,0 +1,4 @@
+x =
+    "part one part two"
+
+x
\ No newline at end of file
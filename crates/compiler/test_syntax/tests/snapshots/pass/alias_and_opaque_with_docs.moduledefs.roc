@@ -0,0 +1,6 @@
+## A person's age,
+## in years
+Age : U32
+
+## Kept secret
+Secret := Str
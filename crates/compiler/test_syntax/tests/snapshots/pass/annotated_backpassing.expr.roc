@@ -0,0 +1,2 @@
+x : I64 <- f
+x
\ No newline at end of file
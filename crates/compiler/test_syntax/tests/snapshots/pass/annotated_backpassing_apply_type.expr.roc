@@ -0,0 +1,2 @@
+result : Result a b <- Task.attempt task
+result
\ No newline at end of file
@@ -0,0 +1,7 @@
+a : Str
+a =
+    x = "x"
+    x
+b : Str
+b = a
+b
\ No newline at end of file
@@ -0,0 +1,3 @@
+x
+    : Str
+x = ""
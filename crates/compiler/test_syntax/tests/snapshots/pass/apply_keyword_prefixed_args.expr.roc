@@ -0,0 +1 @@
+f isEmpty asList thenDo
\ No newline at end of file
@@ -0,0 +1 @@
+Task.await task \result -> result
\ No newline at end of file
@@ -0,0 +1,5 @@
+foo =
+    Task.await task \result ->
+        x = result
+        x
+foo
\ No newline at end of file
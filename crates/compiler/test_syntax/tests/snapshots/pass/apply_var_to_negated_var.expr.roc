@@ -0,0 +1 @@
+x -y
\ No newline at end of file
@@ -0,0 +1,2 @@
+x <- f
+g x
\ No newline at end of file
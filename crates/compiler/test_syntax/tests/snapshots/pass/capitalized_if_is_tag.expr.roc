@@ -0,0 +1 @@
+If cond
\ No newline at end of file
@@ -0,0 +1 @@
+If Then Else
\ No newline at end of file
@@ -0,0 +1 @@
+\{ x } as r -> x
\ No newline at end of file
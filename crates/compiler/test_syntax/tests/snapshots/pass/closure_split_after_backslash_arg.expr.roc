@@ -0,0 +1,2 @@
+List.map xs \
+    x -> x
\ No newline at end of file
@@ -0,0 +1,5 @@
+f = \ # comment
+    x
+    -> x
+
+f
\ No newline at end of file
@@ -0,0 +1,4 @@
+f = \ # comment
+    x -> x
+
+f
\ No newline at end of file
@@ -0,0 +1,3 @@
+f = \x -> x
+
+f
\ No newline at end of file
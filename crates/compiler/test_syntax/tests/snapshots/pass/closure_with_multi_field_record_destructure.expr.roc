@@ -0,0 +1 @@
+\{ x, y } -> x
\ No newline at end of file
@@ -0,0 +1 @@
+# just notes
@@ -0,0 +1,3 @@
+a <- p
+b <- q
+r a b
\ No newline at end of file
@@ -0,0 +1,5 @@
+expect
+    x
+    == 1
+expect y == 2
+x + y
\ No newline at end of file
@@ -0,0 +1,3 @@
+Foo : A
+Bar : B
+Baz a : List a
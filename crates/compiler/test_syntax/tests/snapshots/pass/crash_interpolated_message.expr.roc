@@ -0,0 +1,3 @@
+when result is
+    Ok x -> x
+    Err reason -> crash "failed: $(Inspect.toStr reason)"
\ No newline at end of file
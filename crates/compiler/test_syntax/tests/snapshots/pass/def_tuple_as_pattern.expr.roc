@@ -0,0 +1,2 @@
+(a, b) as pair = getPair
+pair
\ No newline at end of file
@@ -0,0 +1,2 @@
+x = 1
+if x then 1 else 2
\ No newline at end of file
@@ -0,0 +1,3 @@
+x = 1
+when x is
+    _ -> x
\ No newline at end of file
@@ -0,0 +1,3 @@
+## Not about Foo
+
+Foo : Bar
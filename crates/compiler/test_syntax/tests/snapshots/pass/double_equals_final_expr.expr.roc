@@ -0,0 +1,2 @@
+x = 1
+x == 5
\ No newline at end of file
@@ -0,0 +1,3 @@
+expect a && b
+
+42
\ No newline at end of file
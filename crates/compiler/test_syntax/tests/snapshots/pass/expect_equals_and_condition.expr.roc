@@ -0,0 +1,3 @@
+expect a == 1 && b == 2
+
+42
\ No newline at end of file
@@ -0,0 +1,2 @@
+expect-fx x == 1
+x
\ No newline at end of file
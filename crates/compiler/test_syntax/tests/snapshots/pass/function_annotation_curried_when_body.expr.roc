@@ -0,0 +1,6 @@
+f : a -> b -> c where a implements Eq
+f = \x -> \y ->
+    when x is
+        _ if y -> x
+        _ -> x
+f
\ No newline at end of file
@@ -0,0 +1,3 @@
+f : a -> (b -> c)
+f = \x -> \y -> x
+f
\ No newline at end of file
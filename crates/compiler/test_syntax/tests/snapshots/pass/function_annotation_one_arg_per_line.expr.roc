@@ -0,0 +1,5 @@
+f :
+    I64,
+    Str
+    -> Bool
+f
\ No newline at end of file
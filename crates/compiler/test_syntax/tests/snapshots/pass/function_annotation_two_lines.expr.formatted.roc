@@ -0,0 +1,4 @@
+f : I64,
+    Str
+    -> Bool
+f
\ No newline at end of file
@@ -0,0 +1,3 @@
+f : I64,
+    Str -> Bool
+f
\ No newline at end of file
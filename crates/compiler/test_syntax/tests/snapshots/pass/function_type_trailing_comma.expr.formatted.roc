@@ -0,0 +1,4 @@
+f : a, b -> c
+f = \x, y -> x
+
+f
\ No newline at end of file
@@ -0,0 +1,7 @@
+f :
+    Str,
+    List U8
+    -> Result {} [Oops]
+f = \_, _ -> Ok {}
+
+f
\ No newline at end of file
@@ -0,0 +1,2 @@
+f : Str, Str -> {} | [Read, Write]
+f
\ No newline at end of file
@@ -0,0 +1,3 @@
+f : Str
+    -> {} | Effect
+f
\ No newline at end of file
@@ -0,0 +1 @@
+0x1.8p3
\ No newline at end of file
@@ -0,0 +1 @@
+0xAp-2
\ No newline at end of file
@@ -0,0 +1 @@
+0x0F
\ No newline at end of file
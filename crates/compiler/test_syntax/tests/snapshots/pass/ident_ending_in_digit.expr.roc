@@ -0,0 +1 @@
+foo2
\ No newline at end of file
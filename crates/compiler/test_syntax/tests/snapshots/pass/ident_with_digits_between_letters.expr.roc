@@ -0,0 +1 @@
+x1y2
\ No newline at end of file
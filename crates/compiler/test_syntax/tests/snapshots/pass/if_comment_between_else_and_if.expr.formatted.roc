@@ -0,0 +1,9 @@
+if a then
+    1
+else if
+    # c
+    b
+then
+    2
+else
+    3
\ No newline at end of file
@@ -0,0 +1,7 @@
+if a then
+    1
+else # c
+if b then
+    2
+else
+    3
\ No newline at end of file
@@ -0,0 +1 @@
+if a && b then x else y
\ No newline at end of file
@@ -0,0 +1 @@
+if a == 1 && b == 2 then x else y
\ No newline at end of file
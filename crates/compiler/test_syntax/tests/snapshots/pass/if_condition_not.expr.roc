@@ -0,0 +1 @@
+if !a then x else y
\ No newline at end of file
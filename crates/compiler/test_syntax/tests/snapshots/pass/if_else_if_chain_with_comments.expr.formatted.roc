@@ -0,0 +1,19 @@
+if a then
+    1
+else if
+    # not a
+    b
+then
+    2
+else if
+    # neither
+    c
+then
+    3
+else if
+    # last
+    d
+then
+    4
+else
+    5
\ No newline at end of file
@@ -0,0 +1,14 @@
+if a then
+    1
+else # not a
+if b then
+    2
+else
+# neither
+if c then
+    3
+else if # last
+    d then
+    4
+else
+    5
\ No newline at end of file
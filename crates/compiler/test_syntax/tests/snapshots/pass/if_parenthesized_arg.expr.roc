@@ -0,0 +1 @@
+f (if c then a else b) x
\ No newline at end of file
@@ -0,0 +1 @@
+if iffy then thenable else elsewhere
\ No newline at end of file
@@ -0,0 +1 @@
+import Json exposing [map as jsonMap, Decoder as JsonDecoder, decode]
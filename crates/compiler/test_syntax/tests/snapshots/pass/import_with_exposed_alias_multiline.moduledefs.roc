@@ -0,0 +1,5 @@
+import Json exposing [
+    map as jsonMap,
+    # Decoding
+    Decoder as JsonDecoder,
+]
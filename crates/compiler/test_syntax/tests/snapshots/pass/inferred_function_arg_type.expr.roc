@@ -0,0 +1,2 @@
+foo : _ -> Str
+foo
\ No newline at end of file
@@ -0,0 +1,2 @@
+foo : { x : _ }*
+foo
\ No newline at end of file
@@ -0,0 +1,2 @@
+foo : List _
+foo
\ No newline at end of file
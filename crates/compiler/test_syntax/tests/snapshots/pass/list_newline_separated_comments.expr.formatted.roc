@@ -0,0 +1,9 @@
+words = [
+    # the first word
+    "hello",
+    Str.concat "wor" "ld",
+    # a call
+    "!",
+]
+
+words
\ No newline at end of file
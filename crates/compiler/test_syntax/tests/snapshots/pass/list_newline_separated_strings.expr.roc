@@ -0,0 +1,4 @@
+[
+    "a"
+    "b"
+]
\ No newline at end of file
@@ -0,0 +1 @@
+[a, ..rest]
\ No newline at end of file
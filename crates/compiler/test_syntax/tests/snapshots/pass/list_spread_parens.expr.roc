@@ -0,0 +1 @@
+[..xs, b, ..(List.reverse ys)]
\ No newline at end of file
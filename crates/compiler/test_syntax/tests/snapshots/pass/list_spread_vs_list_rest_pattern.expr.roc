@@ -0,0 +1,2 @@
+when [a, ..rest] is
+    [x, .. as tail] -> tail
\ No newline at end of file
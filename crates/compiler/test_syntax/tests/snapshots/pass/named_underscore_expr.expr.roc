@@ -0,0 +1 @@
+_x
\ No newline at end of file
@@ -0,0 +1 @@
+-0.0e0
\ No newline at end of file
@@ -0,0 +1,7 @@
+f = \a ->
+    y
+        : Str
+    y = a
+    y
+
+f
\ No newline at end of file
@@ -0,0 +1 @@
+\x -> \y -> x + y
\ No newline at end of file
@@ -0,0 +1,4 @@
+f =
+    a = 1
+    a + 1
+f
\ No newline at end of file
@@ -0,0 +1,7 @@
+a =
+    b =
+        c = 1
+        c
+    b
+d = a
+d
\ No newline at end of file
@@ -0,0 +1,5 @@
+f =
+    x = 1
+    when x is
+        _ -> x
+f
\ No newline at end of file
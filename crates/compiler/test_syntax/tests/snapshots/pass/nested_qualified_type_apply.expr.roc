@@ -0,0 +1,4 @@
+x : A.B.C x
+x = y
+
+x
\ No newline at end of file
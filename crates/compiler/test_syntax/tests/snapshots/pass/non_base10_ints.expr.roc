@@ -0,0 +1 @@
+[0b1010, 0o17, 0xFF, 0xdead_BEEF, 0b1111_0000u8, -0x7Fi8]
\ No newline at end of file
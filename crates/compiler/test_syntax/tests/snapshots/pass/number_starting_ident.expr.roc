@@ -0,0 +1 @@
+2foo
\ No newline at end of file
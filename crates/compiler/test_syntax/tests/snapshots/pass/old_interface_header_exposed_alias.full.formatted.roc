@@ -0,0 +1,5 @@
+module [foo]
+
+import Json exposing [map as jsonMap, Decoder as JsonDecoder]
+
+foo = jsonMap
@@ -0,0 +1,3 @@
+interface Foo exposes [foo] imports [Json.{ map as jsonMap, Decoder as JsonDecoder }]
+
+foo = jsonMap
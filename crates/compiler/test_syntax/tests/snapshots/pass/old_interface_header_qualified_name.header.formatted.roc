@@ -0,0 +1 @@
+module [a, b, MyType]
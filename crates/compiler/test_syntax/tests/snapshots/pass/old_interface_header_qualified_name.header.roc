@@ -0,0 +1 @@
+interface Foo.Bar exposes [a, b, MyType] imports []
@@ -1,5 +1,5 @@
 x = foo {
-bar
+    bar
 :
 blah
 }
@@ -0,0 +1,6 @@
+result =
+    list
+    |> f
+    |> g
+
+result
\ No newline at end of file
@@ -0,0 +1,4 @@
+x : Json.Decoder a
+x = Json.decoder
+
+x
\ No newline at end of file
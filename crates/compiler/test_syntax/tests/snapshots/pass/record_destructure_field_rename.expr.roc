@@ -0,0 +1,3 @@
+{ userId: id } = record
+
+id
\ No newline at end of file
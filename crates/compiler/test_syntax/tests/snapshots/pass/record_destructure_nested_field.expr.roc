@@ -0,0 +1,3 @@
+{ point: { x, y }, z } = record
+
+x + y + z
\ No newline at end of file
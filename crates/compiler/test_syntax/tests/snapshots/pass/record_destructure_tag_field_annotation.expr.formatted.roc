@@ -0,0 +1,2 @@
+{ x: Int } : Str
+1
\ No newline at end of file
@@ -0,0 +1,3 @@
+{
+    x: if c then 1 else 2,
+}
\ No newline at end of file
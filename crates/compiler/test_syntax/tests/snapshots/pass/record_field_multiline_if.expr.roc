@@ -0,0 +1,8 @@
+{
+    x:
+        if c then
+            1
+        else
+            2,
+    y: 3,
+}
\ No newline at end of file
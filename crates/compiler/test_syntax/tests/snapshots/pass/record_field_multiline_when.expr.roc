@@ -0,0 +1,7 @@
+{
+    x:
+        when c is
+            A -> 1
+            B -> 2,
+    y: 3,
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+x =
+    {
+        a: 1,
+        b: 2,
+    }
+x
\ No newline at end of file
@@ -0,0 +1,3 @@
+f : { x : I64, y }
+
+f
\ No newline at end of file
@@ -0,0 +1,2 @@
+f : { x : I64 }*
+f
\ No newline at end of file
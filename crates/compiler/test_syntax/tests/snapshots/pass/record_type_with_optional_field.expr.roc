@@ -0,0 +1,2 @@
+f : { name : Str, age ? I64 }
+f
\ No newline at end of file
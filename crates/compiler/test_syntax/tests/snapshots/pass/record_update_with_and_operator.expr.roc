@@ -0,0 +1 @@
+{ r & x: a && b }
\ No newline at end of file
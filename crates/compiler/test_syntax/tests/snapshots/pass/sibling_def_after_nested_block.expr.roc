@@ -0,0 +1,6 @@
+a =
+    x = 1
+    y = 2
+    x + y
+b = a
+b
\ No newline at end of file
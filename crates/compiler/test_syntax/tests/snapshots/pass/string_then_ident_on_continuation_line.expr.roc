@@ -0,0 +1,5 @@
+x =
+    "part one"
+        foo
+
+x
\ No newline at end of file
@@ -0,0 +1 @@
+"\x41\0\x7e"
\ No newline at end of file
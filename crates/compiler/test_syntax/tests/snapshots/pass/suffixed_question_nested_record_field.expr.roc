@@ -0,0 +1 @@
+rec.a.b?
\ No newline at end of file
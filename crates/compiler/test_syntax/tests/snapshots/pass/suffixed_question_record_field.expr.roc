@@ -0,0 +1 @@
+rec.name?
\ No newline at end of file
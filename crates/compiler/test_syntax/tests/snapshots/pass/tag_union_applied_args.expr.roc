@@ -0,0 +1,3 @@
+f : [Pair a b, Node (List (Tree a))]
+
+f
\ No newline at end of file
@@ -0,0 +1,3 @@
+Tree a : [Node (Tree a) (Tree a), Leaf a]
+
+x
\ No newline at end of file
@@ -0,0 +1,7 @@
+Color : [
+    # primary
+    Red,
+    Green U8,
+    Blue,
+    # more to come
+]a
@@ -0,0 +1,2 @@
+f : [A]*
+f
\ No newline at end of file
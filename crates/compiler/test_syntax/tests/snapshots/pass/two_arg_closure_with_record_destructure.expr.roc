@@ -0,0 +1 @@
+\{ x }, z -> x
\ No newline at end of file
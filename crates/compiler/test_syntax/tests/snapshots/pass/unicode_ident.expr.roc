@@ -0,0 +1 @@
+café
\ No newline at end of file
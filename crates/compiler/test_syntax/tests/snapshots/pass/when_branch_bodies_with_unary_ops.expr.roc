@@ -0,0 +1,4 @@
+when a is
+    A -> -x
+    B -> !flag
+    _ -> -1
\ No newline at end of file
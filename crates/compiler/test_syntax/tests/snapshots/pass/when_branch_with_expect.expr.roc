@@ -0,0 +1,6 @@
+when x is
+    Ok v ->
+        expect v > 0
+        v
+
+    Err _ -> 0
\ No newline at end of file
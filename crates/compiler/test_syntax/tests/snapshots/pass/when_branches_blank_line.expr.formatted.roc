@@ -0,0 +1,3 @@
+when x is
+    A -> 1
+    B -> 2
\ No newline at end of file
@@ -0,0 +1,6 @@
+when x is
+    A ->
+        1
+
+    B ->
+        2
\ No newline at end of file
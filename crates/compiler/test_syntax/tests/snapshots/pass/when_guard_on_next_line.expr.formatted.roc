@@ -0,0 +1,3 @@
+when x is
+    Ok n if n > 0 -> n
+    _ -> 0
\ No newline at end of file
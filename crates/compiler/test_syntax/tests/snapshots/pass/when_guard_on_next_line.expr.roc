@@ -0,0 +1,4 @@
+when x is
+    Ok n
+        if n > 0 -> n
+    _ -> 0
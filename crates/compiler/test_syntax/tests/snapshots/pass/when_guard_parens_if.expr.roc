@@ -0,0 +1,3 @@
+when x is
+    n if (if n > 0 then Bool.true else Bool.false) -> 1
+    _ -> 2
\ No newline at end of file
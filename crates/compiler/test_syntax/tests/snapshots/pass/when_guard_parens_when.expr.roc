@@ -0,0 +1,5 @@
+when x is
+    n if (when n is
+        0 -> Bool.false
+        _ -> Bool.true) -> 1
+    _ -> 2
\ No newline at end of file
@@ -0,0 +1,5 @@
+when
+    longExpr
+        |> transform
+is
+    _ -> 1
\ No newline at end of file
@@ -0,0 +1 @@
+when -1 is _ -> 1
\ No newline at end of file
@@ -0,0 +1,7 @@
+when x is
+    A ->
+        when y is
+            B -> 1
+            C -> 2
+
+    D -> 3
\ No newline at end of file
@@ -0,0 +1,3 @@
+when x is
+    n | m if n > m -> n
+    _ -> 0
\ No newline at end of file
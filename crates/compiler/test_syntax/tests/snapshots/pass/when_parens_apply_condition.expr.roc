@@ -0,0 +1,2 @@
+when (f y) is
+    A -> 1
\ No newline at end of file
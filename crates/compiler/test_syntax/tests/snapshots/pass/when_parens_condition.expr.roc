@@ -0,0 +1,2 @@
+when (x) is
+    A -> 1
\ No newline at end of file
@@ -0,0 +1,4 @@
+when opt is
+    Just x -> x
+    (a, b) -> a
+    _ -> 0
\ No newline at end of file
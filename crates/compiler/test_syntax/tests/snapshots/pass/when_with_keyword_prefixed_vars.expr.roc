@@ -0,0 +1,3 @@
+when whenever is
+    isnt -> iffy
+    thenable -> elsewhere
\ No newline at end of file
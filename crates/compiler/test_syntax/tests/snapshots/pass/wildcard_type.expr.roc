@@ -0,0 +1,2 @@
+f : *
+f
\ No newline at end of file
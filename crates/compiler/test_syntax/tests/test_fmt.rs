@@ -4148,6 +4148,28 @@ mod test_fmt {
         ));
     }
 
+    #[test]
+    fn when_guard_multiline() {
+        expr_formats_to(
+            indoc!(
+                r"
+                when x is
+                    n | m if
+                      n > m -> n
+                    _ -> 0
+                "
+            ),
+            indoc!(
+                r"
+                when x is
+                    n | m if
+                        n > m -> n
+                    _ -> 0
+                "
+            ),
+        );
+    }
+
     // ACCESSOR
 
     #[test]
@@ -4849,6 +4871,18 @@ mod test_fmt {
         ));
     }
 
+    #[test]
+    fn module_with_only_comments() {
+        module_formats_same(indoc!(
+            r"
+                module [Bar]
+
+                # Bar is coming soon
+
+                # so is Baz"
+        ));
+    }
+
     #[test]
     fn module_exposing_multiline() {
         module_formats_same(indoc!(
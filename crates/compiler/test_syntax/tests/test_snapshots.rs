@@ -187,58 +187,126 @@ mod test_snapshots {
         fail/ability_first_demand_not_indented_enough.expr,
         fail/ability_non_signature_expression.expr,
         fail/alias_or_opaque_fail.expr,
+        fail/annotated_backpassing_bad_type.expr,
+        fail/apply_keyword_as_arg.expr,
+        fail/apply_keyword_is_arg.expr,
+        fail/apply_keyword_then_arg.expr,
+        fail/apply_trailing_closure_outdented_body.expr,
         fail/backpassing_after_annotation.expr,
+        fail/binary_int_invalid_digit.expr,
         fail/bound_variable.expr,
+        fail/capitalized_if_not_conditional.expr,
         fail/comment_with_tab.expr,
+        fail/def_as_pattern_missing_equals.expr,
         fail/def_missing_final_expression.expr,
+        fail/def_number_leading_plus.expr,
+        fail/def_or_pattern.expr,
         fail/def_without_newline.expr,
         fail/deprecated_interpolated_string.expr,
+        fail/double_equals_def.expr,
         fail/double_plus.expr,
         fail/elm_function_syntax.expr,
+        fail/empty_interpolation.expr,
         fail/empty_or_pattern.expr,
         fail/error_inline_alias_argument_uppercase.expr,
         fail/error_inline_alias_not_an_alias.expr,
         fail/error_inline_alias_qualified.expr,
+        fail/expect_condition_not_indented.expr,
+        fail/expect_spaced_minus_fx.expr,
+        fail/expect_without_final_expr.expr,
         fail/expr_to_pattern_fail.expr,
         fail/expression_indentation_end.expr,
+        fail/function_annotation_outdented_arg.expr,
+        fail/function_type_leading_comma.expr,
+        fail/function_type_missing_effect.expr,
+        fail/hex_float_without_exponent.expr,
+        fail/hex_int_invalid_digit.expr,
         fail/if_guard_without_condition.expr,
+        fail/if_missing_condition.expr,
         fail/if_missing_else.expr,
         fail/if_outdented_else_branch.expr,
         fail/if_outdented_then.expr,
+        fail/if_unparenthesized_arg.expr,
+        fail/import_with_exposed_missing_alias.moduledefs,
+        fail/import_with_exposed_type_lowercase_alias.moduledefs,
+        fail/import_with_exposed_value_uppercase_alias.moduledefs,
         fail/import_with_lowercase_alias.moduledefs,
         fail/imports_missing_comma.header,
         fail/inline_hastype.expr,
+        fail/interface_header_lowercase_module_segment.header,
         fail/invalid_operator.expr,
         fail/lambda_double_comma.expr,
         fail/lambda_extra_comma.expr,
         fail/lambda_leading_comma.expr,
         fail/lambda_missing_indent.expr,
+        fail/lambda_or_pattern.expr,
+        fail/leading_equals_operator_stmt.expr,
+        fail/leading_plus_operator.expr,
+        fail/leading_star_operator.expr,
+        fail/list_comma_then_newline_separated.expr,
         fail/list_double_comma.expr,
+        fail/list_leading_comma.expr,
+        fail/list_newline_separated_misaligned.expr,
+        fail/list_newline_then_comma_separated.expr,
         fail/list_pattern_not_terminated.expr,
         fail/list_pattern_weird_rest_pattern.expr,
+        fail/list_spread_apply.expr,
+        fail/list_spread_missing_value.expr,
+        fail/list_spread_space.expr,
+        fail/list_spread_three_dots.expr,
         fail/list_without_end.expr,
         fail/module_params_with_missing_arrow.header,
         fail/module_with_unfinished_params.header,
         fail/multi_no_end.expr,
+        fail/nested_annotation_missing_final_expr.expr,
+        fail/nested_closure_body_underindented.expr,
+        fail/nested_def_missing_final_expr.expr,
+        fail/nested_defs_missing_final_expr.expr,
         fail/newline_before_operator_with_defs.expr,
+        fail/number_leading_plus.expr,
+        fail/number_literal_leading_zero.expr,
+        fail/number_literal_leading_zero_underscore.expr,
+        fail/octal_int_invalid_digit.expr,
         fail/opaque_type_def_with_newline.expr,
         fail/pattern_binds_keyword.expr,
         fail/pattern_in_parens_end.expr,
         fail/pattern_in_parens_end_comma.expr,
         fail/pattern_in_parens_indent_open.expr,
         fail/pattern_in_parens_open.expr,
+        fail/pipeline_operator_not_indented.expr,
+        fail/platform_header_missing_requires.header,
+        fail/platform_header_sections_out_of_order.header,
+        fail/record_binop_field_annotation.expr,
+        fail/record_call_field_annotation.expr,
+        fail/record_field_under_brace.expr,
+        fail/record_leading_comma.expr,
+        fail/record_outdented_field.expr,
+        fail/record_parenthesized_field_name.expr,
+        fail/record_string_field_name.expr,
         fail/record_type_end.expr,
+        fail/record_type_field_missing_type.expr,
         fail/record_type_keyword_field_name.expr,
         fail/record_type_missing_comma.expr,
         fail/record_type_open.expr,
         fail/record_type_open_indent.expr,
+        fail/record_type_optional_field_missing_type.expr,
         fail/record_type_tab.expr,
+        fail/record_update_double_ampersand.expr,
+        fail/single_ampersand_operator.expr,
         fail/single_no_end.expr,
+        fail/spread_in_parens.expr,
+        fail/string_byte_escape_missing_digits.expr,
+        fail/string_byte_escape_not_ascii.expr,
+        fail/suffixed_question_spaced.expr,
         fail/tab_crash.header,
+        fail/tag_union_comma_then_pipe.expr,
         fail/tag_union_end.expr,
         fail/tag_union_lowercase_tag_name.expr,
         fail/tag_union_open.expr,
+        fail/tag_union_pipe_then_comma.expr,
         fail/tag_union_second_lowercase_tag_name.expr,
+        fail/tag_union_trailing_pipe.expr,
+        fail/tag_union_unclosed_paren_arg.expr,
         fail/trailing_operator.expr,
         fail/type_annotation_double_colon.expr,
         fail/type_apply_stray_dot.expr,
@@ -251,20 +319,36 @@ mod test_snapshots {
         fail/underscore_name_type_annotation.expr,
         fail/unfinished_closure_pattern_in_parens.expr,
         fail/unfinished_import_as_or_exposing.moduledefs,
+        fail/unicode_escape_with_braces.expr,
         fail/unicode_not_hex.expr,
         fail/weird_escape.expr,
+        fail/when_branch_def_outdented_final_expr.expr,
+        fail/when_branch_expect_outdented_result.expr,
+        fail/when_branch_expect_under_pattern.expr,
+        fail/when_double_bar_alternative.expr,
+        fail/when_guard_outdented.expr,
+        fail/when_guard_when_without_parens.expr,
         fail/when_missing_arrow.expr,
+        fail/when_nested_misaligned_branch.expr,
         fail/when_outdented_branch.expr,
+        fail/when_outdented_is.expr,
         fail/when_over_indented_int.expr,
         fail/when_over_indented_underscore.expr,
+        fail/when_unparenthesized_arg.expr,
+        fail/when_without_branches.expr,
+        fail/when_without_branches_in_def.expr,
         fail/where_type_variable.expr,
         fail/wild_case_arrow.expr,
+        fail/wildcard_type_applied.expr,
         malformed/bad_opaque_ref.expr,
+        malformed/field_access_chain_trailing_dot.expr,
+        malformed/field_access_trailing_dot.expr,
         malformed/malformed_ident_due_to_underscore.expr,
         malformed/malformed_pattern_field_access.expr, // See https://github.com/roc-lang/roc/issues/399
         malformed/malformed_pattern_module_name.expr, // See https://github.com/roc-lang/roc/issues/399
         malformed/module_dot_tuple.expr,
         malformed/qualified_tag.expr,
+        malformed/qualified_type_lowercase_segment.expr,
         malformed/underscore_expr_in_def.expr,
         pass/ability_demand_signature_is_multiline.expr,
         pass/ability_multi_line.expr,
@@ -272,25 +356,47 @@ mod test_snapshots {
         pass/ability_two_in_a_row.expr,
         pass/add_var_with_spaces.expr,
         pass/add_with_spaces.expr,
+        pass/adjacent_strings_on_continuation_lines.expr,
+        pass/alias_and_opaque_with_docs.moduledefs,
         pass/ann_closed_union.expr,
         pass/ann_open_union.expr,
+        pass/annotated_backpassing.expr,
+        pass/annotated_backpassing_apply_type.expr,
         pass/annotated_record_destructure.expr,
+        pass/annotated_sibling_def_after_nested_block.expr,
         pass/annotated_tag_destructure.expr,
         pass/annotated_tuple_destructure.expr,
+        pass/annotation_colon_aligned_on_next_line.moduledefs,
+        pass/annotation_colon_on_next_line.moduledefs,
+        pass/annotation_without_body_at_end.moduledefs,
+        pass/apply_keyword_prefixed_args.expr,
         pass/apply_parenthetical_tag_args.expr,
         pass/apply_tag.expr,
         pass/apply_three_args.expr,
+        pass/apply_trailing_closure.expr,
+        pass/apply_trailing_multiline_closure.expr,
         pass/apply_two_args.expr,
         pass/apply_unary_negation.expr,
         pass/apply_unary_not.expr,
+        pass/apply_var_to_negated_var.expr,
         pass/arg_pattern_as.expr,
+        pass/backpassing_then_final_expr.expr,
         pass/basic_apply.expr,
         pass/basic_docs.expr,
         pass/basic_field.expr,
         pass/basic_tag.expr,
         pass/basic_tuple.expr,
         pass/basic_var.expr,
+        pass/body_equals_aligned_on_next_line.moduledefs,
+        pass/capitalized_if_is_tag.expr,
+        pass/capitalized_keywords_are_tags.expr,
         pass/closure_in_binop_with_spaces.expr,
+        pass/closure_param_as_pattern.expr,
+        pass/closure_split_after_backslash_arg.expr,
+        pass/closure_split_after_backslash_comment.expr,
+        pass/closure_split_after_backslash_def.expr,
+        pass/closure_with_multi_field_record_destructure.expr,
+        pass/closure_with_record_destructure.expr,
         pass/closure_with_underscores.expr,
         pass/comma_prefixed_indented_record.expr,
         pass/comment_after_annotation.expr,
@@ -301,29 +407,46 @@ mod test_snapshots {
         pass/comment_before_equals_def.expr,
         pass/comment_before_op.expr,
         pass/comment_inside_empty_list.expr,
+        pass/comment_only.moduledefs,
         pass/comment_with_non_ascii.expr,
+        pass/consecutive_backpassing_then_final_expr.expr,
+        pass/consecutive_expects_with_final_expr.expr,
+        pass/consecutive_type_aliases.moduledefs,
         pass/control_characters_in_scalar.expr,
         pass/crash.expr,
+        pass/crash_interpolated_message.expr,
         pass/dbg.expr,
         pass/dbg_stmt.expr,
         pass/dbg_stmt_multiline.expr,
+        pass/def_tuple_as_pattern.expr,
+        pass/defs_final_if.expr,
+        pass/defs_final_when.expr,
+        pass/defs_final_when_one_line.expr,
         pass/defs_suffixed_middle_extra_indents.moduledefs,
         pass/destructure_tag_assignment.expr,
         pass/docs.expr,
+        pass/docs_separated_from_alias.moduledefs,
+        pass/double_equals_final_expr.expr,
         pass/empty_app_header.header,
         pass/empty_hosted_header.header,
         pass/empty_list.expr,
+        pass/empty_list_with_space.expr,
         pass/empty_module_header.header,
         pass/empty_package_header.header,
         pass/empty_platform_header.header,
         pass/empty_record.expr,
         pass/empty_record_update.expr,
+        pass/empty_record_with_newline.expr,
+        pass/empty_record_with_space.expr,
         pass/empty_string.expr,
         pass/equals.expr,
         pass/equals_with_spaces.expr,
         pass/expect.expr,
+        pass/expect_and_condition.expr,
         pass/expect_defs.moduledefs,
+        pass/expect_equals_and_condition.expr,
         pass/expect_fx.moduledefs,
+        pass/expect_fx_stmt.expr,
         pass/expect_single_line.expr,
         pass/extra_newline.expr,
         pass/extra_newline_in_parens.expr,
@@ -331,19 +454,45 @@ mod test_snapshots {
         pass/fn_with_record_arg.expr,
         pass/full_app_header.header,
         pass/full_app_header_trailing_commas.header,
+        pass/function_annotation_curried_when_body.expr,
+        pass/function_annotation_curried_with_body.expr,
+        pass/function_annotation_one_arg_per_line.expr,
+        pass/function_annotation_three_lines.expr,
+        pass/function_annotation_two_lines.expr,
         pass/function_effect_types.header,
+        pass/function_type_trailing_comma.expr,
+        pass/function_type_trailing_comma_multiline.expr,
+        pass/function_type_with_effect.expr,
+        pass/function_type_with_effect_multiline.expr,
         pass/function_with_tuple_ext_type.expr,
         pass/function_with_tuple_type.expr,
+        pass/hex_float.expr,
+        pass/hex_float_negative_exponent.expr,
+        pass/hex_int_with_leading_zero_digit.expr,
         pass/highest_float.expr,
         pass/highest_int.expr,
+        pass/ident_ending_in_digit.expr,
+        pass/ident_with_digits_between_letters.expr,
+        pass/if_comment_between_else_and_if.expr,
+        pass/if_condition_and.expr,
+        pass/if_condition_equals_and.expr,
+        pass/if_condition_not.expr,
         pass/if_def.expr,
+        pass/if_else_if_chain_with_comments.expr,
+        pass/if_parenthesized_arg.expr,
+        pass/if_with_keyword_prefixed_vars.expr,
         pass/import.moduledefs,
         pass/import_from_package.moduledefs,
         pass/import_with_alias.moduledefs,
         pass/import_with_comments.moduledefs,
         pass/import_with_exposed.moduledefs,
+        pass/import_with_exposed_alias.moduledefs,
+        pass/import_with_exposed_alias_multiline.moduledefs,
         pass/import_with_params.moduledefs,
         pass/indented_after_multi_backpassing.expr,
+        pass/inferred_function_arg_type.expr,
+        pass/inferred_record_field_type.expr,
+        pass/inferred_type_arg.expr,
         pass/ingested_file.moduledefs,
         pass/inline_import.expr,
         pass/inline_ingested_file.expr,
@@ -354,9 +503,17 @@ mod test_snapshots {
         pass/list_closing_indent_not_enough.expr,
         pass/list_closing_same_indent_no_trailing_comma.expr,
         pass/list_closing_same_indent_with_trailing_comma.expr,
+        pass/list_comma_separated_multiline.expr,
         pass/list_minus_newlines.expr,
+        pass/list_newline_separated.expr,
+        pass/list_newline_separated_comments.expr,
+        pass/list_newline_separated_strings.expr,
         pass/list_pattern_weird_indent.expr,
         pass/list_patterns.expr,
+        pass/list_spread.expr,
+        pass/list_spread_parens.expr,
+        pass/list_spread_vs_list_rest_pattern.expr,
+        pass/list_trailing_comma.expr,
         pass/lowest_float.expr,
         pass/lowest_int.expr,
         pass/minimal_app_header.header,
@@ -382,15 +539,27 @@ mod test_snapshots {
         pass/multiline_type_signature_with_comment.expr,
         pass/multiple_fields.expr,
         pass/multiple_operators.expr,
+        pass/named_underscore_expr.expr,
         pass/neg_inf_float.expr,
         pass/negate_multiline_string.expr,
         pass/negate_multiline_string_with_quote.expr,
         pass/negative_float.expr,
+        pass/negative_hex_float.expr,
         pass/negative_in_apply_def.expr,
         pass/negative_int.expr,
+        pass/negative_zero_float.expr,
+        pass/negative_zero_float_with_exponent.expr,
+        pass/negative_zero_int.expr,
+        pass/nested_annotation_colon_on_next_line.expr,
+        pass/nested_closure.expr,
+        pass/nested_closure_multiline.expr,
         pass/nested_def_annotation.moduledefs,
+        pass/nested_def_then_final_expr.expr,
+        pass/nested_defs_deeper_indent.expr,
+        pass/nested_defs_final_when.expr,
         pass/nested_if.expr,
         pass/newline_after_equals.expr, // Regression test for https://github.com/roc-lang/roc/issues/51
+        pass/nested_qualified_type_apply.expr,
         pass/newline_after_mul.expr,
         pass/newline_after_paren.expr,
         pass/newline_after_sub.expr,
@@ -402,13 +571,17 @@ mod test_snapshots {
         pass/newline_in_type_def.expr,
         pass/newline_inside_empty_list.expr,
         pass/newline_singleton_list.expr,
+        pass/non_base10_ints.expr,
         pass/nonempty_hosted_header.header,
         pass/nonempty_package_header.header,
         pass/nonempty_platform_header.header,
         pass/not_multiline_string.expr,
         pass/number_literal_suffixes.expr,
+        pass/number_starting_ident.expr,
         pass/old_app_header.full,
         pass/old_interface_header.header,
+        pass/old_interface_header_exposed_alias.full,
+        pass/old_interface_header_qualified_name.header,
         pass/one_backpassing.expr,
         pass/one_char_string.expr,
         pass/one_def.expr,
@@ -442,6 +615,8 @@ mod test_snapshots {
         pass/pattern_as_list_rest.expr,
         pass/pattern_as_spaces.expr,
         pass/pattern_with_space_in_parens.expr, // https://github.com/roc-lang/roc/issues/929
+        pass/pipeline_three_lines.expr,
+        pass/pipeline_two_lines.expr,
         pass/pizza_bang.moduledefs,
         pass/pizza_dbg.expr,
         pass/plus_if.expr,
@@ -451,19 +626,37 @@ mod test_snapshots {
         pass/positive_int.expr,
         pass/provides_type.header,
         pass/qualified_field.expr,
+        pass/qualified_type_apply.expr,
         pass/qualified_var.expr,
         pass/record_access_after_tuple.expr,
+        pass/record_accessor_function.expr,
         pass/record_builder.expr,
         pass/record_builder_ignored_fields.expr,
         pass/record_destructure_def.expr,
+        pass/record_destructure_field_rename.expr,
+        pass/record_destructure_nested_field.expr,
+        pass/record_destructure_tag_field_annotation.expr,
+        pass/record_field_if_on_next_line.expr,
+        pass/record_field_multiline_if.expr,
+        pass/record_field_multiline_when.expr,
         pass/record_func_type_decl.expr,
+        pass/record_multiline_fields.expr,
+        pass/record_pun_destructure_and_value.expr,
+        pass/record_trailing_comma.expr,
+        pass/record_type_pun.expr,
+        pass/record_type_wildcard_ext.expr,
         pass/record_type_with_function.expr,
+        pass/record_type_with_optional_field.expr,
         pass/record_update.expr,
+        pass/record_update_single_ampersand.expr,
+        pass/record_update_with_and_operator.expr,
         pass/record_updater_literal_apply.expr,
         pass/record_updater_var_apply.expr,
+        pass/record_value_with_colon_field.expr,
         pass/record_with_if.expr,
         pass/requires_type.header,
         pass/separate_defs.moduledefs,
+        pass/sibling_def_after_nested_block.expr,
         pass/single_arg_closure.expr,
         pass/single_underscore_closure.expr,
         pass/space_before_colon.full,
@@ -472,8 +665,12 @@ mod test_snapshots {
         pass/spaces_inside_empty_list.expr,
         pass/standalone_module_defs.moduledefs,
         pass/str_block_multiple_newlines.expr,
+        pass/string_then_ident_on_continuation_line.expr,
+        pass/string_with_byte_and_null_escapes.expr,
         pass/string_without_escape.expr,
         pass/sub_var_with_spaces.expr,
+        pass/sub_vars_no_spaces.expr,
+        pass/sub_vars_with_spaces.expr,
         pass/sub_with_spaces.expr,
         pass/suffixed_bang.expr,
         pass/suffixed_bang_multiple_defs.moduledefs,
@@ -483,17 +680,27 @@ mod test_snapshots {
         pass/suffixed_question.expr,
         pass/suffixed_question_multiple_defs.moduledefs,
         pass/suffixed_question_nested.expr,
+        pass/suffixed_question_nested_record_field.expr,
         pass/suffixed_question_one_def.full,
         pass/suffixed_question_optional_last.full,
+        pass/suffixed_question_record_field.expr,
         pass/tag_pattern.expr,
+        pass/tag_union_applied_args.expr,
+        pass/tag_union_comma_separated.expr,
+        pass/tag_union_parens_args.expr,
+        pass/tag_union_pipe_separated.expr,
+        pass/tag_union_pipe_separated_multiline.moduledefs,
+        pass/tag_union_wildcard_ext.expr,
         pass/ten_times_eleven.expr,
         pass/three_arg_closure.expr,
         pass/tuple_access_after_ident.expr,
         pass/tuple_access_after_record.expr,
         pass/tuple_accessor_function.expr,
+        pass/tuple_spread.expr,
         pass/tuple_type.expr,
         pass/tuple_type_ext.expr,
         pass/two_arg_closure.expr,
+        pass/two_arg_closure_with_record_destructure.expr,
         pass/two_backpassing.expr,
         pass/two_branch_when.expr,
         pass/two_spaced_def.expr,
@@ -508,6 +715,7 @@ mod test_snapshots {
         pass/unary_not_with_parens.expr,
         pass/underscore_backpassing.expr,
         pass/underscore_in_assignment_pattern.expr,
+        pass/unicode_ident.expr,
         pass/value_def_confusion.expr,
         pass/var_else.expr,
         pass/var_if.expr,
@@ -515,15 +723,35 @@ mod test_snapshots {
         pass/var_minus_two.expr,
         pass/var_then.expr,
         pass/var_when.expr,
+        pass/when_branch_bodies_with_unary_ops.expr,
+        pass/when_branch_with_defs.expr,
+        pass/when_branch_with_expect.expr,
+        pass/when_branches_blank_line.expr,
+        pass/when_branches_blank_lines_multiline.expr,
+        pass/when_guard_if_without_parens.expr,
+        pass/when_guard_on_next_line.expr,
+        pass/when_guard_parens_if.expr,
+        pass/when_guard_parens_when.expr,
         pass/when_if_guard.expr,
         pass/when_in_assignment.expr,
         pass/when_in_function.expr,
         pass/when_in_function_python_style_indent.expr,
         pass/when_in_parens.expr,
         pass/when_in_parens_indented.expr,
+        pass/when_multiline_piped_condition.expr,
+        pass/when_negated_var_condition.expr,
+        pass/when_negative_number_condition.expr,
+        pass/when_negative_number_condition_one_line.expr,
+        pass/when_nested_in_branch.expr,
+        pass/when_or_patterns_with_guard.expr,
+        pass/when_parens_apply_condition.expr,
+        pass/when_parens_apply_condition_one_line.expr,
+        pass/when_parens_condition.expr,
+        pass/when_parenthesized_patterns.expr,
         pass/when_result_list.expr,
         pass/when_with_alternative_patterns.expr,
         pass/when_with_function_application.expr,
+        pass/when_with_keyword_prefixed_vars.expr,
         pass/when_with_negative_numbers.expr,
         pass/when_with_numbers.expr,
         pass/when_with_records.expr,
@@ -536,6 +764,8 @@ mod test_snapshots {
         pass/where_clause_non_function.expr,
         pass/where_clause_on_newline.expr,
         pass/where_ident.expr,
+        pass/whitespace_only.moduledefs,
+        pass/wildcard_type.expr,
         pass/zero_float.expr,
         pass/zero_int.expr,
         // END SNAPSHOTS (for automatic test detection via `env ROC_SNAPSHOT_TEST_OVERWRITE=1 cargo test`)
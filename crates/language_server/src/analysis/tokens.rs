@@ -6,8 +6,9 @@ use roc_module::called_via::{BinOp, UnaryOp};
 use roc_parse::{
     ast::{
         AbilityImpls, AbilityMember, AssignedField, Collection, Defs, Expr, Header, Implements,
-        ImplementsAbilities, ImplementsAbility, ImplementsClause, Pattern, PatternAs, Spaced,
-        StrLiteral, Tag, TypeAnnotation, TypeDef, TypeHeader, ValueDef, WhenBranch,
+        ImplementsAbilities, ImplementsAbility, ImplementsClause, ImportExposedName, Pattern,
+        PatternAs, Spaced, StrLiteral, Tag, TypeAnnotation, TypeDef, TypeHeader, ValueDef,
+        WhenBranch,
     },
     header::{
         AppHeader, ExposedName, HostedHeader, ImportsEntry, ModuleHeader, ModuleName, ModuleParams,
@@ -105,6 +106,12 @@ impl HasToken for ExposedName<'_> {
     }
 }
 
+impl HasToken for ImportExposedName<'_> {
+    fn token(&self) -> Token {
+        self.name.token()
+    }
+}
+
 impl HasToken for PackageName<'_> {
     fn token(&self) -> Token {
         Token::Module
@@ -386,9 +393,12 @@ impl IterTokens for PlatformRequires<'_> {
 impl IterTokens for Loc<TypeAnnotation<'_>> {
     fn iter_tokens<'a>(&self, arena: &'a Bump) -> BumpVec<'a, Loc<Token>> {
         match self.value {
-            TypeAnnotation::Function(params, ret) => (params.iter_tokens(arena).into_iter())
-                .chain(ret.iter_tokens(arena))
-                .collect_in(arena),
+            TypeAnnotation::Function(params, ret, effect) => {
+                (params.iter_tokens(arena).into_iter())
+                    .chain(ret.iter_tokens(arena))
+                    .chain(effect.iter().flat_map(|effect| effect.iter_tokens(arena)))
+                    .collect_in(arena)
+            }
             TypeAnnotation::Apply(_mod, _type, args) => args.iter_tokens(arena),
             TypeAnnotation::BoundVariable(_) => onetoken(Token::Type, self.region, arena),
             TypeAnnotation::As(ty, _, as_ty) => (ty.iter_tokens(arena).into_iter())
@@ -671,6 +681,7 @@ impl IterTokens for Loc<Expr<'_>> {
                 .collect_in(arena),
             Expr::Record(rcd) => rcd.iter_tokens(arena),
             Expr::Tuple(tup) => tup.iter_tokens(arena),
+            Expr::Spread(e) => e.iter_tokens(arena),
             Expr::RecordBuilder { mapper, fields } => (mapper.iter_tokens(arena).into_iter())
                 .chain(fields.iter().flat_map(|f| f.iter_tokens(arena)))
                 .collect_in(arena),
@@ -685,7 +696,8 @@ impl IterTokens for Loc<Expr<'_>> {
             Expr::Defs(defs, exprs) => (defs.iter_tokens(arena).into_iter())
                 .chain(exprs.iter_tokens(arena))
                 .collect_in(arena),
-            Expr::Backpassing(patterns, e1, e2) => (patterns.iter_tokens(arena).into_iter())
+            Expr::Backpassing(lhs, e1, e2) => (lhs.patterns.iter_tokens(arena).into_iter())
+                .chain(lhs.ann.iter_tokens(arena))
                 .chain(e1.iter_tokens(arena))
                 .chain(e2.iter_tokens(arena))
                 .collect_in(arena),
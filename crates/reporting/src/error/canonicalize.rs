@@ -8,7 +8,7 @@ use roc_problem::can::{
     ScopeModuleSource, ShadowKind,
 };
 use roc_problem::Severity;
-use roc_region::all::{LineColumn, LineColumnRegion, LineInfo, Loc, Region};
+use roc_region::all::{LineColumn, LineColumnRegion, LineInfo, Loc, Position, Region};
 use roc_types::types::AliasKind;
 use std::path::PathBuf;
 
@@ -777,6 +777,26 @@ pub fn can_problem<'b>(
 
             title = INVALID_UNICODE.to_string();
         }
+        Problem::UnicodeSurrogateCodePt(region) => {
+            doc = to_unicode_surrogate_report_help(alloc, lines, region, severity);
+
+            title = INVALID_UNICODE.to_string();
+        }
+        Problem::RawTabInString(region) => {
+            doc = alloc.stack([
+                alloc.reflow("This string contains a tab character:"),
+                alloc.region(lines.convert_region(region), severity),
+                alloc.concat([
+                    alloc.reflow(
+                        "Tabs are easy to mistake for spaces, so it's clearer to write them as ",
+                    ),
+                    alloc.parser_suggestion("\\t"),
+                    alloc.reflow(" instead."),
+                ]),
+            ]);
+
+            title = "TAB IN STRING".to_string();
+        }
         Problem::InvalidInterpolation(region) => {
             doc = alloc.stack([
                 alloc.reflow("This string interpolation is invalid:"),
@@ -1389,6 +1409,26 @@ fn to_invalid_optional_value_report<'b>(
     }
 }
 
+fn to_unicode_surrogate_report_help<'b>(
+    alloc: &'b RocDocAllocator<'b>,
+    lines: &LineInfo,
+    region: Region,
+    severity: Severity,
+) -> RocDocBuilder<'b> {
+    alloc.stack([
+        alloc.reflow("This unicode code point is a surrogate:"),
+        alloc.region(lines.convert_region(region), severity),
+        alloc.concat([
+            alloc.reflow("Code points from "),
+            alloc.parser_suggestion("\\u(D800)"),
+            alloc.reflow(" to "),
+            alloc.parser_suggestion("\\u(DFFF)"),
+            alloc.reflow(" are reserved for UTF-16."),
+        ]),
+        alloc.reflow("Learn more about working with unicode in roc at TODO"),
+    ])
+}
+
 fn to_invalid_optional_value_report_help<'b>(
     alloc: &'b RocDocAllocator<'b>,
     lines: &LineInfo,
@@ -1415,6 +1455,30 @@ fn to_invalid_optional_value_report_help<'b>(
     ])
 }
 
+fn to_trailing_dot_report_help<'b>(
+    alloc: &'b RocDocAllocator<'b>,
+    lines: &LineInfo,
+    pos: Position,
+    surroundings: Region,
+    severity: Severity,
+) -> RocDocBuilder<'b> {
+    let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
+
+    alloc.stack([
+        alloc.reflow(r"I encountered a dot with nothing after it:"),
+        alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+        alloc.concat([
+            alloc.reflow("I was expecting a field name after the "),
+            alloc.parser_suggestion("."),
+            alloc.reflow(", like "),
+            alloc.parser_suggestion("user.name"),
+            alloc.reflow(", or a tuple index, like "),
+            alloc.parser_suggestion("pair.0"),
+            alloc.reflow("."),
+        ]),
+    ])
+}
+
 fn to_bad_ident_expr_report<'b>(
     alloc: &'b RocDocAllocator<'b>,
     lines: &LineInfo,
@@ -1426,6 +1490,9 @@ fn to_bad_ident_expr_report<'b>(
 
     match bad_ident {
         Start(_) | Space(_, _) => unreachable!("these are handled in the parser"),
+        TrailingDot(pos) => {
+            to_trailing_dot_report_help(alloc, lines, pos, surroundings, severity)
+        }
         WeirdDotAccess(pos) | StrayDot(pos) => {
             let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
 
@@ -1646,6 +1713,7 @@ fn to_bad_ident_pattern_report<'b>(
 
     match bad_ident {
         Start(_) | Space(_, _) => unreachable!("these are handled in the parser"),
+        TrailingDot(pos) => to_trailing_dot_report_help(alloc, lines, pos, surroundings, severity),
         WeirdDotAccess(pos) | StrayDot(pos) => {
             let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
 
@@ -2341,6 +2409,11 @@ fn pretty_runtime_error<'b>(
                 region
             );
         }
+        RuntimeError::UnicodeSurrogateCodePt(region) => {
+            doc = to_unicode_surrogate_report_help(alloc, lines, region, severity);
+
+            title = INVALID_UNICODE;
+        }
         RuntimeError::InvalidInterpolation(region) => {
             todo!(
                 "TODO runtime error for an invalid string interpolation at region {:?}",
@@ -2522,6 +2595,19 @@ fn pretty_runtime_error<'b>(
 
             title = "OPTIONAL FIELD IN RECORD BUILDER";
         }
+        RuntimeError::UnsupportedSpread(region) => {
+            doc = alloc.stack([
+                alloc.reflow("This spread is not supported yet:"),
+                alloc.region(lines.convert_region(region), severity),
+                alloc.concat([
+                    alloc.reflow("Lists and tuples can't splat other collections into them. Try "),
+                    alloc.parser_suggestion("List.concat"),
+                    alloc.reflow(" to combine lists instead."),
+                ]),
+            ]);
+
+            title = "SPREAD NOT SUPPORTED";
+        }
     }
 
     (doc, title)
@@ -3,7 +3,7 @@ use roc_problem::Severity;
 use roc_region::all::{LineColumn, LineColumnRegion, LineInfo, Position, Region};
 use std::path::PathBuf;
 
-use crate::report::{Report, RocDocAllocator, RocDocBuilder};
+use crate::report::{Annotation, Report, RocDocAllocator, RocDocBuilder};
 use ven_pretty::DocAllocator;
 
 pub fn parse_problem<'a>(
@@ -229,6 +229,17 @@ fn to_expr_report<'a>(
                     alloc.parser_suggestion("|>"),
                     alloc.reflow(" instead?"),
                 ],
+                "&" => vec![
+                    alloc.reflow("Maybe you want "),
+                    alloc.parser_suggestion("&&"),
+                    alloc.reflow(" for a boolean and, or "),
+                    alloc.parser_suggestion("Num.bitwiseAnd"),
+                    alloc.reflow(" for a bitwise and? A single "),
+                    alloc.parser_suggestion("&"),
+                    alloc.reflow(" is only used in record updates, like "),
+                    alloc.parser_suggestion("{ user & name: \"Sam\" }"),
+                    alloc.reflow("."),
+                ],
                 "++" => vec![
                     alloc.reflow("To concatenate two lists or strings, try using "),
                     alloc.parser_suggestion("List.concat"),
@@ -503,6 +514,176 @@ fn to_expr_report<'a>(
             }
         }
 
+        EExpr::UnparenthesizedKeywordArg(keyword, pos) => {
+            let surroundings = Region::new(start, *pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(*pos));
+
+            let (article, suggestion) = if *keyword == roc_parse::keyword::IF {
+                ("An ", "f (if cond then a else b)")
+            } else {
+                ("A ", "f (when x is ...)")
+            };
+
+            let doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow(r"I am partway through parsing an expression, but I got stuck on this "),
+                    alloc.keyword(keyword),
+                    alloc.reflow(":"),
+                ]),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow(article),
+                    alloc.keyword(keyword),
+                    alloc.reflow(" expression can only be passed to a function when it is wrapped in parentheses, like "),
+                    alloc.parser_suggestion(suggestion),
+                    alloc.reflow("."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "MISSING PARENTHESES".to_string(),
+                severity,
+            }
+        }
+
+        EExpr::UnaryPlus(region) => {
+            let surroundings = Region::new(start, region.end());
+            let region = lines.convert_region(*region);
+
+            let doc = alloc.stack([
+                alloc.reflow(r"I am partway through parsing an expression, but I got stuck here:"),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow("Roc numbers can't start with a "),
+                    alloc.parser_suggestion("+"),
+                    alloc.reflow(", so try removing it. For example, write "),
+                    alloc.parser_suggestion("5"),
+                    alloc.reflow(" instead of "),
+                    alloc.parser_suggestion("+5"),
+                    alloc.reflow("."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "LEADING PLUS SIGN".to_string(),
+                severity,
+            }
+        }
+
+        EExpr::MissingLeftOperand(op, region) => {
+            let surroundings = Region::new(start, region.end());
+            let region = lines.convert_region(*region);
+
+            let doc = alloc.stack([
+                alloc.reflow(r"I am partway through parsing an expression, but I got stuck here:"),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow("This "),
+                    alloc.binop(*op),
+                    alloc.reflow(" operator needs a value on its left, like "),
+                    alloc
+                        .string(format!("a {op} b"))
+                        .annotate(Annotation::ParserSuggestion),
+                    alloc.reflow("."),
+                ]),
+                alloc.concat([
+                    alloc.tip(),
+                    alloc.reflow("To pass an operator to a function, wrap it in a lambda, like "),
+                    alloc
+                        .string(format!("\\a, b -> a {op} b"))
+                        .annotate(Annotation::ParserSuggestion),
+                    alloc.reflow("."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "MISSING LEFT OPERAND".to_string(),
+                severity,
+            }
+        }
+
+        EExpr::RecordNotAPattern(region) => {
+            let surroundings = Region::new(start, region.end());
+            let region = lines.convert_region(*region);
+
+            let doc = alloc.stack([
+                alloc.reflow(
+                    r"I am partway through parsing a definition, but this record is not a pattern:",
+                ),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow("Only a record destructure like "),
+                    alloc.parser_suggestion("{ x, y }"),
+                    alloc.reflow(" can go before a "),
+                    alloc.parser_suggestion(":"),
+                    alloc.reflow(". To give a record value a type, name it first, like "),
+                    alloc.parser_suggestion("point : { x : I64 }"),
+                    alloc.reflow("."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "RECORD IS NOT A PATTERN".to_string(),
+                severity,
+            }
+        }
+
+        EExpr::BadSpread(kind, region) => {
+            use roc_parse::parser::ESpread;
+
+            let surroundings = Region::new(start, region.end());
+            let region = lines.convert_region(*region);
+
+            let hint = match kind {
+                ESpread::InParens => alloc.concat([
+                    alloc.reflow("A spread can only be an element of a list, like "),
+                    alloc.parser_suggestion("[a, ..rest]"),
+                    alloc.reflow(", or of a tuple with other elements, like "),
+                    alloc.parser_suggestion("(a, ..rest)"),
+                    alloc.reflow("."),
+                ]),
+                ESpread::ThreeDots => alloc.concat([
+                    alloc.reflow("A spread is written with two dots, like "),
+                    alloc.parser_suggestion("..rest"),
+                    alloc.reflow("."),
+                ]),
+                ESpread::NeedsParens => alloc.concat([
+                    alloc.reflow("A spread only takes the single value right after it. "),
+                    alloc.reflow("To spread a bigger expression, wrap it in parentheses, like "),
+                    alloc.parser_suggestion("..(List.reverse xs)"),
+                    alloc.reflow("."),
+                ]),
+                ESpread::MissingValue => alloc.concat([
+                    alloc.reflow("I was expecting a value right after the "),
+                    alloc.parser_suggestion(".."),
+                    alloc.reflow(", like "),
+                    alloc.parser_suggestion("..rest"),
+                    alloc.reflow("."),
+                ]),
+            };
+
+            let doc = alloc.stack([
+                alloc.reflow(r"I am partway through parsing a spread, but I got stuck here:"),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                hint,
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "BAD SPREAD".to_string(),
+                severity,
+            }
+        }
+
         EExpr::Colon(pos) => {
             let surroundings = Region::new(start, *pos);
             let region = LineColumnRegion::from_pos(lines.convert_pos(*pos));
@@ -554,6 +735,18 @@ fn to_expr_report<'a>(
             to_malformed_number_literal_report(alloc, lines, filename, pos)
         }
 
+        &EExpr::Number(ENumber::LeadingZero(region), _) => {
+            to_leading_zero_number_literal_report(alloc, lines, filename, region)
+        }
+
+        &EExpr::Number(ENumber::HexFloatWithoutExponent(region), _) => {
+            to_hex_float_without_exponent_report(alloc, lines, filename, region)
+        }
+
+        &EExpr::Number(ENumber::InvalidDigit(base, region), _) => {
+            to_invalid_digit_number_literal_report(alloc, lines, filename, base, region)
+        }
+
         EExpr::Ability(err, pos) => to_ability_def_report(alloc, lines, filename, err, *pos),
 
         EExpr::IndentEnd(pos) => {
@@ -628,6 +821,35 @@ fn to_expr_report<'a>(
                 severity,
             }
         }
+        EExpr::IndentOperator(pos) => {
+            let surroundings = Region::new(start, *pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(*pos));
+
+            let doc = alloc.stack([
+                alloc.reflow(r"I am partway through parsing an expression, but I got stuck here:"),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc
+                        .reflow(r"This operator starts a new line, but it is not indented enough "),
+                    alloc.reflow(r"to continue the expression above it. "),
+                    alloc.reflow(r"Try indenting it further, like so:"),
+                ]),
+                alloc
+                    .vcat([
+                        alloc.parser_suggestion("result ="),
+                        alloc.parser_suggestion("    list"),
+                        alloc.parser_suggestion("    |> List.map f"),
+                    ])
+                    .indent(4),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "UNINDENTED OPERATOR".to_string(),
+                severity,
+            }
+        }
         EExpr::UnexpectedComma(pos) => {
             let surroundings = Region::new(start, *pos);
             let region = LineColumnRegion::from_pos(lines.convert_pos(*pos));
@@ -678,6 +900,36 @@ fn to_expr_report<'a>(
                 severity,
             }
         }
+        EExpr::DoubleEqualsDef(region) => {
+            let surroundings = Region::new(start, region.end());
+
+            let doc = alloc.stack([
+                alloc.reflow(r"This looks like a definition, but it uses == instead of =:"),
+                alloc.region_with_subregion(
+                    lines.convert_region(surroundings),
+                    lines.convert_region(*region),
+                    severity,
+                ),
+                alloc.concat([
+                    alloc.reflow(r"The "),
+                    alloc.parser_suggestion("=="),
+                    alloc.reflow(r" operator compares two values, so I read this as the final "),
+                    alloc.reflow(r"expression, but there is more code after it. "),
+                    alloc.reflow(r"To define a value, use a single "),
+                    alloc.parser_suggestion("="),
+                    alloc.reflow(r", like "),
+                    alloc.parser_suggestion("x = 5"),
+                    alloc.reflow(r"."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "DOUBLE EQUALS IN DEFINITION".to_string(),
+                severity,
+            }
+        }
         _ => todo!("unhandled parse error: {:?}", parse_problem),
     }
 }
@@ -686,10 +938,75 @@ fn to_record_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
     filename: PathBuf,
-    _parse_problem: &roc_parse::parser::ERecord<'a>,
+    parse_problem: &roc_parse::parser::ERecord<'a>,
     pos: Position,
     start: Position,
 ) -> Report<'a> {
+    use roc_parse::parser::ERecord;
+
+    match *parse_problem {
+        ERecord::UnexpectedComma(region) => {
+            return to_unexpected_comma_report(
+                alloc,
+                lines,
+                filename,
+                region,
+                start,
+                "record",
+                "field",
+                "{ x: 1, }",
+            );
+        }
+        ERecord::DoubleAmpersand(region) => {
+            let severity = Severity::RuntimeError;
+            let surroundings = Region::new(start, region.end());
+            let region = lines.convert_region(region);
+
+            let doc = alloc.stack([
+                alloc
+                    .reflow(r"I am partway through parsing a record update, but I got stuck here:"),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow("A record update uses a single "),
+                    alloc.parser_suggestion("&"),
+                    alloc.reflow(", like "),
+                    alloc.parser_suggestion("{ user & name: \"Sam\" }"),
+                    alloc.reflow("."),
+                ]),
+            ]);
+
+            return Report {
+                filename,
+                doc,
+                title: "DOUBLE AMPERSAND".to_string(),
+                severity,
+            };
+        }
+        ERecord::NonIdentifierField(pos) => {
+            let severity = Severity::RuntimeError;
+            let surroundings = Region::new(start, pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
+
+            let doc = alloc.stack([
+                alloc.reflow(r"I am partway through parsing a record, but I got stuck here:"),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow("Record field names must be plain lowercase names, like "),
+                    alloc.parser_suggestion("{ x: 1 }"),
+                    alloc.reflow(". They can't be strings or computed from an expression."),
+                ]),
+            ]);
+
+            return Report {
+                filename,
+                doc,
+                title: "WEIRD FIELD NAME".to_string(),
+                severity,
+            };
+        }
+        _ => {}
+    }
+
     let surroundings = Region::new(start, pos);
     let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
 
@@ -708,6 +1025,48 @@ fn to_record_report<'a>(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
+fn to_unexpected_comma_report<'a>(
+    alloc: &'a RocDocAllocator<'a>,
+    lines: &LineInfo,
+    filename: PathBuf,
+    region: Region,
+    start: Position,
+    collection: &'static str,
+    element: &'static str,
+    example: &'static str,
+) -> Report<'a> {
+    let severity = Severity::RuntimeError;
+    let surroundings = Region::new(start, region.end());
+    let region = lines.convert_region(region);
+
+    let doc = alloc.stack([
+        alloc.concat([
+            alloc.reflow("I am partway through parsing a "),
+            alloc.reflow(collection),
+            alloc.reflow(", but I got stuck on this comma:"),
+        ]),
+        alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+        alloc.concat([
+            alloc.reflow("There is no "),
+            alloc.reflow(element),
+            alloc.reflow(" before this comma, so try removing it. "),
+            alloc.reflow("Commas go after each "),
+            alloc.reflow(element),
+            alloc.reflow(", and a trailing comma is fine, like "),
+            alloc.parser_suggestion(example),
+            alloc.reflow("."),
+        ]),
+    ]);
+
+    Report {
+        filename,
+        doc,
+        title: "UNEXPECTED COMMA".to_string(),
+        severity,
+    }
+}
+
 fn to_lambda_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
@@ -890,9 +1249,11 @@ fn to_lambda_report<'a>(
             pos,
             start,
             alloc.concat([
-                alloc.reflow(r"I just saw a pattern, so I was expecting to see a "),
+                alloc.reflow(r"I just saw a "),
                 alloc.parser_suggestion("->"),
-                alloc.reflow(" next."),
+                alloc.reflow(
+                    r", so I was expecting to see the function body next, indented more than the line the function starts on.",
+                ),
             ]),
         ),
 
@@ -909,6 +1270,34 @@ fn to_lambda_report<'a>(
                 alloc.reflow(r"I was expecting to see a expression next"),
             ]),
         ),
+
+        EClosure::OrPattern(pos) => {
+            let surroundings = Region::new(start, pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
+
+            let doc = alloc.stack([
+                alloc.reflow(
+                    r"I am partway through parsing a function argument list, but I got stuck here:",
+                ),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow("Alternative patterns like "),
+                    alloc.parser_suggestion("Red | Green"),
+                    alloc.reflow(" can only be used in "),
+                    alloc.keyword("when"),
+                    alloc.reflow(" branches. Try matching on the argument with a "),
+                    alloc.keyword("when"),
+                    alloc.reflow(" in the function body instead."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "UNEXPECTED OR-PATTERN".to_string(),
+                severity,
+            }
+        }
     }
 }
 
@@ -994,6 +1383,8 @@ fn to_str_report<'a>(
                         suggestion("A tab: ", "\\t"),
                         suggestion("An escaped quote: ", "\\\""),
                         suggestion("An escaped backslash: ", "\\\\"),
+                        suggestion("A null character: ", "\\0"),
+                        suggestion("An ASCII character: ", "\\x41"),
                         suggestion("A unicode code point: ", "\\u(00FF)"),
                     ])
                     .indent(4),
@@ -1032,6 +1423,84 @@ fn to_str_report<'a>(
                 severity,
             }
         }
+        EString::ByteEscapeDigits(region) => {
+            let surroundings = Region::new(start, region.end());
+
+            let doc = alloc.stack([
+                alloc.reflow(r"I am partway through parsing a byte escape, but I got stuck here:"),
+                alloc.region_with_subregion(
+                    lines.convert_region(surroundings),
+                    lines.convert_region(region),
+                    severity,
+                ),
+                alloc.concat([
+                    alloc.reflow(r"I was expecting exactly two hexadecimal digits, like "),
+                    alloc.parser_suggestion("\\x41"),
+                    alloc.reflow(" or "),
+                    alloc.parser_suggestion("\\x0A"),
+                    alloc.text("."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "WEIRD BYTE ESCAPE".to_string(),
+                severity,
+            }
+        }
+        EString::NonAsciiByteEscape(region) => {
+            let surroundings = Region::new(start, region.end());
+
+            let doc = alloc.stack([
+                alloc.reflow(r"This byte escape is not an ASCII character:"),
+                alloc.region_with_subregion(
+                    lines.convert_region(surroundings),
+                    lines.convert_region(region),
+                    severity,
+                ),
+                alloc.concat([
+                    alloc.reflow(r"Strings are UTF-8, so a "),
+                    alloc.parser_suggestion("\\x"),
+                    alloc.reflow(r" escape can only be used for ASCII characters, from "),
+                    alloc.parser_suggestion("\\x00"),
+                    alloc.reflow(" to "),
+                    alloc.parser_suggestion("\\x7F"),
+                    alloc
+                        .reflow(r". For other characters, use a unicode code point instead, like "),
+                    alloc.parser_suggestion("\\u(00FF)"),
+                    alloc.text("."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "NON-ASCII BYTE ESCAPE".to_string(),
+                severity,
+            }
+        }
+        EString::BadEscapes(bad_escapes) => {
+            let docs = bad_escapes.iter().map(|bad_escape| {
+                to_str_report(alloc, lines, filename.clone(), bad_escape, start).doc
+            });
+
+            let doc = alloc.stack(
+                std::iter::once(alloc.concat([
+                    alloc.reflow(r"This string literal has "),
+                    alloc.text(bad_escapes.len().to_string()),
+                    alloc.reflow(r" escape sequences that I could not parse."),
+                ]))
+                .chain(docs),
+            );
+
+            Report {
+                filename,
+                doc,
+                title: "BAD ESCAPES".to_string(),
+                severity,
+            }
+        }
         EString::FormatEnd(pos) => {
             let surroundings = Region::new(start, pos);
             let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
@@ -1349,72 +1818,88 @@ fn to_list_report<'a>(
             pos,
         ),
 
-        EList::Open(pos) | EList::End(pos) => {
-            match what_is_next(alloc.src_lines, lines.convert_pos(pos)) {
-                Next::Other(Some(',')) => {
-                    let surroundings = Region::new(start, pos);
-                    let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
+        EList::UnexpectedComma(region) => to_unexpected_comma_report(
+            alloc,
+            lines,
+            filename,
+            region,
+            start,
+            "list",
+            "list entry",
+            "[1, 2,]",
+        ),
 
-                    let doc = alloc.stack([
-                        alloc.reflow(
-                            r"I am partway through started parsing a list, but I got stuck here:",
-                        ),
-                        alloc.region_with_subregion(
-                            lines.convert_region(surroundings),
-                            region,
-                            severity,
-                        ),
-                        alloc.concat([
-                            alloc
-                                .reflow(r"I was expecting to see a list entry before this comma, "),
-                            alloc.reflow(r"so try adding a list entry"),
-                            alloc.reflow(r" and see if that helps?"),
-                        ]),
-                    ]);
-                    Report {
-                        filename,
-                        doc,
-                        title: "UNFINISHED LIST".to_string(),
-                        severity,
-                    }
-                }
-                _ => {
-                    let surroundings = Region::new(start, pos);
-                    let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
+        EList::MixedSeparators(pos) => {
+            let surroundings = Region::new(start, pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
 
-                    let doc = alloc.stack([
-                        alloc.reflow(
-                            r"I am partway through started parsing a list, but I got stuck here:",
-                        ),
-                        alloc.region_with_subregion(
-                            lines.convert_region(surroundings),
-                            region,
-                            severity,
-                        ),
-                        alloc.concat([
-                            alloc.reflow(
-                                r"I was expecting to see a closing square bracket before this, ",
-                            ),
-                            alloc.reflow(r"so try adding a "),
-                            alloc.parser_suggestion("]"),
-                            alloc.reflow(r" and see if that helps?"),
-                        ]),
-                        alloc.concat([
-                            alloc.note("When "),
-                            alloc.reflow(r"I get stuck like this, "),
-                            alloc.reflow(r"it usually means that there is a missing parenthesis "),
-                            alloc.reflow(r"or bracket somewhere earlier. "),
-                            alloc.reflow(r"It could also be a stray keyword or operator."),
-                        ]),
-                    ]);
+            let doc = alloc.stack([
+                alloc.reflow(r"I am partway through parsing a list, but I got stuck here:"),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow(r"The elements of a list are separated either by commas, like "),
+                    alloc.parser_suggestion("[1, 2]"),
+                    alloc.reflow(", or by putting each one on its own line. "),
+                    alloc.reflow(r"This list does both, so try using just one of those!"),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "MIXED LIST SEPARATORS".to_string(),
+                severity,
+            }
+        }
+
+        EList::MisalignedElement(pos) => {
+            let surroundings = Region::new(start, pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
+
+            let doc = alloc.stack([
+                alloc.reflow(r"I am partway through parsing a list, but I got stuck here:"),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow(r"When the elements of a list are separated by putting each one on its own line, they all need to start in the same column. "),
+                    alloc.reflow(r"Try lining this element up with the first one!"),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "MISALIGNED LIST ELEMENT".to_string(),
+                severity,
+            }
+        }
+
+        EList::Open(pos) | EList::End(pos) => {
+            let surroundings = Region::new(start, pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
+
+            let doc = alloc.stack([
+                alloc.reflow(r"I am partway through started parsing a list, but I got stuck here:"),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow(r"I was expecting to see a closing square bracket before this, "),
+                    alloc.reflow(r"so try adding a "),
+                    alloc.parser_suggestion("]"),
+                    alloc.reflow(r" and see if that helps?"),
+                ]),
+                alloc.concat([
+                    alloc.note("When "),
+                    alloc.reflow(r"I get stuck like this, "),
+                    alloc.reflow(r"it usually means that there is a missing parenthesis "),
+                    alloc.reflow(r"or bracket somewhere earlier. "),
+                    alloc.reflow(r"It could also be a stray keyword or operator."),
+                ]),
+            ]);
 
-                    Report {
-                        filename,
-                        doc,
-                        title: "UNFINISHED LIST".to_string(),
-                        severity,
-                    }
-                }
+            Report {
+                filename,
+                doc,
+                title: "UNFINISHED LIST".to_string(),
+                severity,
             }
         }
     }
@@ -1446,7 +1931,40 @@ fn to_dbg_or_expect_report<'a>(
             to_expr_report(alloc, lines, filename, context, e_expr, *continuation_start)
         }
 
-        roc_parse::parser::EExpect::IndentCondition(_) => todo!(),
+        roc_parse::parser::EExpect::IndentCondition(pos) => {
+            let severity = Severity::RuntimeError;
+            let surroundings = Region::new(start, *pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(*pos));
+
+            let (keyword, example) = match node {
+                Node::Dbg => ("dbg", "dbg answer"),
+                _ => ("expect", "expect 1 + 1 == 2"),
+            };
+
+            let doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow(r"I am partway through parsing "),
+                    alloc.keyword(keyword),
+                    alloc.reflow(r", but I got stuck here:"),
+                ]),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow(r"I was expecting to see an expression after the "),
+                    alloc.keyword(keyword),
+                    alloc.reflow(
+                        r" keyword. If it goes on the next line, it must be indented more than the keyword, like",
+                    ),
+                ]),
+                alloc.parser_suggestion(example).indent(4),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "MISSING EXPRESSION".to_string(),
+                severity,
+            }
+        }
     }
 }
 
@@ -1604,6 +2122,9 @@ fn to_import_report<'a>(
                 severity,
             }
         }
+        ExposedAliasCase(region) => {
+            to_exposed_alias_case_report(alloc, lines, filename, *region, start)
+        }
         ExposingListStart(pos) => to_unfinished_import_report(
             alloc,
             lines,
@@ -1709,11 +2230,24 @@ fn to_if_report<'a>(
     parse_problem: &roc_parse::parser::EIf<'a>,
     start: Position,
 ) -> Report<'a> {
-    use roc_parse::parser::EIf;
+    use roc_parse::parser::{EExpr, EIf};
 
     match *parse_problem {
         EIf::Space(error, pos) => to_space_report(alloc, lines, filename, &error, pos),
 
+        EIf::Condition(&EExpr::Start(pos), _) => to_unfinished_if_report(
+            alloc,
+            lines,
+            filename,
+            pos,
+            start,
+            alloc.concat([
+                alloc.reflow(r"I was expecting to see a condition next, like "),
+                alloc.parser_suggestion("if x > 0 then"),
+                alloc.reflow(r"."),
+            ]),
+        ),
+
         EIf::Condition(expr, pos) => to_expr_report(
             alloc,
             lines,
@@ -1884,6 +2418,30 @@ fn to_when_report<'a>(
             }
         }
 
+        EWhen::NoBranches(region) => {
+            let doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow(r"This "),
+                    alloc.keyword("when"),
+                    alloc.reflow(r" expression doesn't have any branches:"),
+                ]),
+                alloc.region(lines.convert_region(region), severity),
+                alloc.concat([
+                    alloc.reflow(r"I was expecting to see at least one branch after the "),
+                    alloc.keyword("is"),
+                    alloc.reflow(r", indented a bit more than the line it is on."),
+                ]),
+                note_for_when_error(alloc),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "MISSING WHEN BRANCHES".to_string(),
+                severity,
+            }
+        }
+
         EWhen::Space(error, pos) => to_space_report(alloc, lines, filename, &error, pos),
 
         EWhen::Branch(expr, pos) => to_expr_report(
@@ -1917,6 +2475,34 @@ fn to_when_report<'a>(
             ]),
         ),
 
+        EWhen::DoubleBar(region) => {
+            let surroundings = Region::new(start, region.end());
+            let region = lines.convert_region(region);
+
+            let doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow(r"I am partway through parsing the patterns of a "),
+                    alloc.keyword("when"),
+                    alloc.reflow(r" branch, but I got stuck here:"),
+                ]),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow("Alternative patterns are separated by a single "),
+                    alloc.parser_suggestion("|"),
+                    alloc.reflow(", like "),
+                    alloc.parser_suggestion("A | B -> 1"),
+                    alloc.reflow("."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "DOUBLE BAR".to_string(),
+                severity,
+            }
+        }
+
         EWhen::IfToken(_pos) => unreachable!("the if-token is optional"),
         EWhen::When(_pos) => unreachable!("another branch would be taken"),
 
@@ -1942,6 +2528,21 @@ fn to_when_report<'a>(
             alloc.concat([alloc.reflow(r"I was expecting to see a expression next")]),
         ),
 
+        EWhen::IndentIs(pos) => to_unfinished_when_report(
+            alloc,
+            lines,
+            filename,
+            pos,
+            start,
+            alloc.concat([
+                alloc.reflow(r"I suspect this "),
+                alloc.keyword("is"),
+                alloc.reflow(r" keyword is not indented enough? It should be indented at least as much as the line containing the "),
+                alloc.keyword("when"),
+                alloc.reflow("."),
+            ]),
+        ),
+
         EWhen::IndentPattern(pos) => to_unfinished_when_report(
             alloc,
             lines,
@@ -2165,6 +2766,15 @@ fn to_pattern_report<'a>(
         &EPattern::NumLiteral(ENumber::End, pos) => {
             to_malformed_number_literal_report(alloc, lines, filename, pos)
         }
+        &EPattern::NumLiteral(ENumber::LeadingZero(region), _) => {
+            to_leading_zero_number_literal_report(alloc, lines, filename, region)
+        }
+        &EPattern::NumLiteral(ENumber::HexFloatWithoutExponent(region), _) => {
+            to_hex_float_without_exponent_report(alloc, lines, filename, region)
+        }
+        &EPattern::NumLiteral(ENumber::InvalidDigit(base, region), _) => {
+            to_invalid_digit_number_literal_report(alloc, lines, filename, base, region)
+        }
         _ => todo!("unhandled parse error: {:?}", parse_problem),
     }
 }
@@ -2534,6 +3144,117 @@ fn to_malformed_number_literal_report<'a>(
     }
 }
 
+fn to_leading_zero_number_literal_report<'a>(
+    alloc: &'a RocDocAllocator<'a>,
+    lines: &LineInfo,
+    filename: PathBuf,
+    region: Region,
+) -> Report<'a> {
+    let severity = Severity::RuntimeError;
+
+    let doc = alloc.stack([
+        alloc.reflow(r"This number literal has a leading zero:"),
+        alloc.region(lines.convert_region(region), severity),
+        alloc.concat([
+            alloc.reflow("Roc does not allow leading zeros in decimal numbers. "),
+            alloc.reflow("If you meant to write an octal number, use the "),
+            alloc.parser_suggestion("0o"),
+            alloc.reflow(" prefix, like "),
+            alloc.parser_suggestion("0o17"),
+            alloc.reflow("."),
+        ]),
+    ]);
+
+    Report {
+        filename,
+        doc,
+        title: "LEADING ZERO".to_string(),
+        severity,
+    }
+}
+
+fn to_hex_float_without_exponent_report<'a>(
+    alloc: &'a RocDocAllocator<'a>,
+    lines: &LineInfo,
+    filename: PathBuf,
+    region: Region,
+) -> Report<'a> {
+    let severity = Severity::RuntimeError;
+
+    let doc = alloc.stack([
+        alloc.reflow(r"This hexadecimal float literal is missing its exponent:"),
+        alloc.region(lines.convert_region(region), severity),
+        alloc.concat([
+            alloc.reflow("Hexadecimal floats need a binary exponent after a "),
+            alloc.parser_suggestion("p"),
+            alloc.reflow(", like "),
+            alloc.parser_suggestion("0x1.8p3"),
+            alloc.reflow(", which is 1.5 times 2 to the power of 3, or "),
+            alloc.parser_suggestion("0x1.8p0"),
+            alloc.reflow(" for just 1.5."),
+        ]),
+    ]);
+
+    Report {
+        filename,
+        doc,
+        title: "MISSING EXPONENT".to_string(),
+        severity,
+    }
+}
+
+fn to_invalid_digit_number_literal_report<'a>(
+    alloc: &'a RocDocAllocator<'a>,
+    lines: &LineInfo,
+    filename: PathBuf,
+    base: roc_parse::ast::Base,
+    region: Region,
+) -> Report<'a> {
+    use roc_parse::ast::Base::*;
+
+    let severity = Severity::RuntimeError;
+
+    let (name, plurals, charset) = match base {
+        Decimal => ("integer", "Integer literals", "0-9"),
+        Octal => ("octal integer", "Octal (base-8) integer literals", "0-7"),
+        Hex => (
+            "hex integer",
+            "Hexadecimal (base-16) integer literals",
+            "0-9, a-f and A-F",
+        ),
+        Binary => (
+            "binary integer",
+            "Binary (base-2) integer literals",
+            "0 and 1",
+        ),
+    };
+
+    let doc = alloc.stack([
+        alloc.concat([
+            alloc.reflow("This "),
+            alloc.text(name),
+            alloc.reflow(" literal contains an invalid digit:"),
+        ]),
+        alloc.region(lines.convert_region(region), severity),
+        alloc.concat([
+            alloc.text(plurals),
+            alloc.reflow(" can only contain the digits "),
+            alloc.text(charset),
+            alloc.text(", or have an integer suffix."),
+        ]),
+        alloc
+            .tip()
+            .append(alloc.reflow("Learn more about number literals at TODO")),
+    ]);
+
+    Report {
+        filename,
+        doc,
+        title: "INVALID DIGIT".to_string(),
+        severity,
+    }
+}
+
 fn to_type_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
@@ -2578,6 +3299,28 @@ fn to_type_report<'a>(
             }
         }
 
+        EType::TFunctionEffect(pos) => {
+            let surroundings = Region::new(start, *pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(*pos));
+
+            let doc = alloc.stack([
+                alloc.reflow(r"I just saw a | after this function's return type, so I was expecting an effect next:"),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow(r"An effect goes at the end of a function type, like "),
+                    alloc.parser_suggestion("Str -> {} | Effect"),
+                    alloc.reflow("."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "MISSING EFFECT".to_string(),
+                severity,
+            }
+        }
+
         EType::TStart(pos) => {
             let surroundings = Region::new(start, *pos);
             let region = LineColumnRegion::from_pos(lines.convert_pos(*pos));
@@ -3070,6 +3813,62 @@ fn to_ttag_union_report<'a>(
             }
         }
 
+        ETypeTagUnion::PipeWithoutTag(pos) => {
+            let surroundings = Region::new(start, pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
+
+            let doc = alloc.stack([
+                alloc.reflow(
+                    r"I am partway through parsing a tag union type, but I got stuck here:",
+                ),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow(r"I was expecting to see a tag after this "),
+                    alloc.parser_suggestion("|"),
+                    alloc.reflow(". Every "),
+                    alloc.parser_suggestion("|"),
+                    alloc.reflow(" comes before a tag, so there is no trailing one, like in "),
+                    alloc.parser_suggestion("[| Red | Green]"),
+                    alloc.reflow("."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "MISSING TAG AFTER PIPE".to_string(),
+                severity,
+            }
+        }
+
+        ETypeTagUnion::MixedSeparators(pos) => {
+            let surroundings = Region::new(start, pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
+
+            let doc = alloc.stack([
+                alloc.reflow(
+                    r"I am partway through parsing a tag union type, but I got stuck here:",
+                ),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow(r"The tags of a tag union are separated either by commas, like "),
+                    alloc.parser_suggestion("[Red, Green]"),
+                    alloc.reflow(", or by a "),
+                    alloc.parser_suggestion("|"),
+                    alloc.reflow(" before each one, like "),
+                    alloc.parser_suggestion("[| Red | Green]"),
+                    alloc.reflow(". Try using just one of those!"),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "MIXED TAG SEPARATORS".to_string(),
+                severity,
+            }
+        }
+
         ETypeTagUnion::Type(tipe, pos) => to_type_report(alloc, lines, filename, tipe, pos),
 
         ETypeTagUnion::Space(error, pos) => to_space_report(alloc, lines, filename, &error, pos),
@@ -3642,6 +4441,39 @@ fn to_header_report<'a>(
             }
         }
 
+        EHeader::UnexpectedSection(expected, region) => {
+            let doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow(
+                        r"I am partway through parsing a platform header, and I was expecting the ",
+                    ),
+                    alloc.keyword(expected),
+                    alloc.reflow(" section here:"),
+                ]),
+                alloc.region(lines.convert_region(*region), severity),
+                alloc.concat([
+                    alloc.reflow("A platform header has all of these sections, in this order: "),
+                    alloc.keyword("requires"),
+                    alloc.reflow(", "),
+                    alloc.keyword("exposes"),
+                    alloc.reflow(", "),
+                    alloc.keyword("packages"),
+                    alloc.reflow(", "),
+                    alloc.keyword("imports"),
+                    alloc.reflow(", and "),
+                    alloc.keyword("provides"),
+                    alloc.reflow("."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "UNEXPECTED HEADER SECTION".to_string(),
+                severity,
+            }
+        }
+
         EHeader::AppName(_, pos) => {
             let surroundings = Region::new(start, *pos);
             let region = LineColumnRegion::from_pos(lines.convert_pos(*pos));
@@ -4045,10 +4877,45 @@ fn to_imports_report<'a>(
             }
         }
 
+        EImports::ExposedAliasCase(region) => {
+            to_exposed_alias_case_report(alloc, lines, filename, region, start)
+        }
+
         _ => todo!("unhandled parse error {:?}", parse_problem),
     }
 }
 
+fn to_exposed_alias_case_report<'a>(
+    alloc: &'a RocDocAllocator<'a>,
+    lines: &LineInfo,
+    filename: PathBuf,
+    region: Region,
+    start: Position,
+) -> Report<'a> {
+    let severity = Severity::RuntimeError;
+    let surroundings = Region::new(start, region.end());
+    let region = lines.convert_region(region);
+
+    let doc = alloc.stack([
+        alloc.reflow(r"This import renames an exposed name to one with different capitalization:"),
+        alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+        alloc.concat([
+            alloc.reflow(r"Values and functions can only be renamed to lowercase names, like "),
+            alloc.parser_suggestion("map as jsonMap"),
+            alloc.reflow(r", and types to uppercase names, like "),
+            alloc.parser_suggestion("Decoder as JsonDecoder"),
+            alloc.reflow("."),
+        ]),
+    ]);
+
+    Report {
+        filename,
+        doc,
+        title: "WEIRD EXPOSED ALIAS".to_string(),
+        severity,
+    }
+}
+
 fn to_requires_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,